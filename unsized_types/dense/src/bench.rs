@@ -0,0 +1,50 @@
+//! `cargo run --release -- --bench`: `gemm` against the naive triple loop
+//!
+//! Both products are timed, and the run panics if they don't agree up to roundoff
+
+use std::f64;
+use std::time::Duration;
+
+use blas;
+
+/// Multiplies two `n x n` matrices with `blas::gemm` and with the triple loop, and prints the
+/// timings and the largest difference between the two products
+pub fn gemm(n: usize) {
+    // Deterministic elements in `[-1, 1]`, so every run does the same work
+    let a = ::Mat::from_fn((n, n), |i, j| ((7 * i + 13 * j) % 17) as f64 / 8. - 1.);
+    let b = ::Mat::from_fn((n, n), |i, j| ((11 * i + 5 * j) % 19) as f64 / 9. - 1.);
+
+    let mut c = ::Mat::zeros((n, n));
+    let fast = Duration::span(|| blas::gemm(1., &a, &b, 0., &mut c));
+
+    let mut naive = ::Mat::zeros((n, n));
+    let slow = Duration::span(|| {
+        for i in 0..n {
+            for j in 0..n {
+                let mut s = 0.;
+
+                for k in 0..n {
+                    s += a[(i, k)] * b[(k, j)];
+                }
+
+                naive[(i, j)] = s;
+            }
+        }
+    });
+
+    let diff = c.zip_with(&naive, |x, y| (x - y).abs())
+        .fold(0., |max, &x| if x > max { x } else { max });
+    // The elements are at most 1 in magnitude, so `sum(|a[i, k] * b[k, j]|) <= n`
+    let tol = n as f64 * f64::EPSILON * n as f64;
+
+    println!("{0}x{0} f64 matrices", n);
+    println!("gemm:        {:.3} s", secs(fast));
+    println!("triple loop: {:.3} s ({:.1}x slower)", secs(slow), secs(slow) / secs(fast));
+    println!("max |gemm - triple loop| = {:e} (tolerance: {:e})", diff, tol);
+
+    assert!(diff <= tol, "`gemm` and the triple loop disagree");
+}
+
+fn secs(d: Duration) -> f64 {
+    d.as_secs() as f64 + d.subsec_nanos() as f64 * 1e-9
+}
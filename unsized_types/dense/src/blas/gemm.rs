@@ -0,0 +1,269 @@
+//! General matrix-matrix multiplication: `C = alpha * A * B + beta * C`
+//!
+//! This follows the structure of the GotoBLAS/BLIS algorithm: the operands are split in blocks
+//! that fit in the caches, each block is packed into a contiguous buffer, and a register-blocked
+//! micro-kernel computes `MR`-by-`NR` tiles of `C`. Packing is also what lets `gemm` work on
+//! arbitrary strided views (submatrices, transposes, reversed views) without copying the whole
+//! operands: each element of `A` and `B` is read from its view once per block.
+
+use std::cmp;
+use std::ops::Mul;
+use std::raw::FatPtr;
+
+use error::Error;
+use num::Scalar;
+use ops::AsStrided;
+
+/// Rows of the `A` blocks, sized to fit in the L2 cache
+const MC: usize = 64;
+/// Columns of the `A` blocks / rows of the `B` blocks, sized to fit in the L1 cache
+const KC: usize = 256;
+/// Columns of the `B` blocks, sized to fit in the L3 cache
+const NC: usize = 4096;
+/// Rows of the micro-kernel tile
+const MR: usize = 4;
+/// Columns of the micro-kernel tile
+const NR: usize = 4;
+
+/// `C = alpha * A * B + beta * C`
+///
+/// If `beta` is zero, `C` is not read, so it may contain garbage (e.g. NaNs)
+pub fn gemm<T>(
+    alpha: T,
+    a: &::strided::Mat<T>,
+    b: &::strided::Mat<T>,
+    beta: T,
+    c: &mut ::strided::Mat<T>,
+) where
+    T: Scalar,
+{
+    let (m, k) = a.size();
+    let n = b.ncols();
+
+    if k != b.nrows() {
+        panic!("{}", Error::InnerSizeMismatch { lhs: a.size(), rhs: b.size() })
+    }
+
+    if c.size() != (m, n) {
+        panic!("{}", Error::SizeMismatch { lhs: c.size(), rhs: (m, n) })
+    }
+
+    if k == 0 || alpha == T::zero() {
+        scale(beta, c);
+        return
+    }
+
+    let mut a_pack = vec![T::zero(); round_up(cmp::min(MC, m), MR) * cmp::min(KC, k)];
+    let mut b_pack = vec![T::zero(); cmp::min(KC, k) * round_up(cmp::min(NC, n), NR)];
+
+    let mut jc = 0;
+    while jc < n {
+        let nc = cmp::min(NC, n - jc);
+
+        let mut pc = 0;
+        while pc < k {
+            let kc = cmp::min(KC, k - pc);
+            // `C` is scaled by `beta` in the first pass, the following passes accumulate
+            let beta = if pc == 0 { beta } else { T::one() };
+
+            pack_b(&b[(pc..pc + kc, jc..jc + nc)], &mut b_pack);
+
+            let mut ic = 0;
+            while ic < m {
+                let mc = cmp::min(MC, m - ic);
+
+                pack_a(&a[(ic..ic + mc, pc..pc + kc)], &mut a_pack);
+
+                macro_kernel(alpha, &a_pack, &b_pack, beta, &mut c[(ic..ic + mc, jc..jc + nc)], kc);
+
+                ic += MC;
+            }
+
+            pc += KC;
+        }
+
+        jc += NC;
+    }
+}
+
+/// Returns the owned `A * B` product
+pub fn matmul<T>(a: &::strided::Mat<T>, b: &::strided::Mat<T>) -> Box<::Mat<T>> where T: Scalar {
+    let mut c = ::Mat::zeros((a.nrows(), b.ncols()));
+
+    gemm(T::one(), a, b, T::zero(), &mut c);
+
+    c
+}
+
+fn round_up(n: usize, multiple: usize) -> usize {
+    (n + multiple - 1) / multiple * multiple
+}
+
+/// `C = beta * C`, without reading `C` if `beta` is zero
+fn scale<T>(beta: T, c: &mut ::strided::Mat<T>) where T: Scalar {
+    if beta == T::zero() {
+        for x in c.iter_mut() {
+            *x = T::zero();
+        }
+    } else if beta != T::one() {
+        for x in c.iter_mut() {
+            *x = beta * *x;
+        }
+    }
+}
+
+/// Packs the `mc`-by-`kc` block `a` into `MR`-row panels, each panel is stored column by column.
+/// The last panel is padded with zeros.
+fn pack_a<T>(a: &::strided::Mat<T>, buf: &mut [T]) where T: Scalar {
+    let (mc, kc) = a.size();
+    let FatPtr { data, info } = a.repr();
+
+    unsafe {
+        let mut dst = buf.as_mut_ptr();
+
+        let mut i0 = 0;
+        while i0 < mc {
+            let mr = cmp::min(MR, mc - i0);
+
+            for l in 0..kc {
+                let src = data.offset(i0 as isize * info.row_stride + l as isize * info.col_stride);
+
+                for i in 0..MR {
+                    *dst.offset(i as isize) = if i < mr {
+                        *src.offset(i as isize * info.row_stride)
+                    } else {
+                        T::zero()
+                    };
+                }
+
+                dst = dst.offset(MR as isize);
+            }
+
+            i0 += MR;
+        }
+    }
+}
+
+/// Packs the `kc`-by-`nc` block `b` into `NR`-column panels, each panel is stored row by row.
+/// The last panel is padded with zeros.
+fn pack_b<T>(b: &::strided::Mat<T>, buf: &mut [T]) where T: Scalar {
+    let (kc, nc) = b.size();
+    let FatPtr { data, info } = b.repr();
+
+    unsafe {
+        let mut dst = buf.as_mut_ptr();
+
+        let mut j0 = 0;
+        while j0 < nc {
+            let nr = cmp::min(NR, nc - j0);
+
+            for l in 0..kc {
+                let src = data.offset(l as isize * info.row_stride + j0 as isize * info.col_stride);
+
+                for j in 0..NR {
+                    *dst.offset(j as isize) = if j < nr {
+                        *src.offset(j as isize * info.col_stride)
+                    } else {
+                        T::zero()
+                    };
+                }
+
+                dst = dst.offset(NR as isize);
+            }
+
+            j0 += NR;
+        }
+    }
+}
+
+/// `C = alpha * A * B + beta * C` where `A` and `B` are packed blocks
+fn macro_kernel<T>(alpha: T, a: &[T], b: &[T], beta: T, c: &mut ::strided::Mat<T>, kc: usize)
+    where T: Scalar,
+{
+    let (mc, nc) = c.size();
+    let FatPtr { data, info } = c.repr();
+    let (rs, cs) = (info.row_stride, info.col_stride);
+
+    unsafe {
+        let mut j0 = 0;
+        while j0 < nc {
+            let nr = cmp::min(NR, nc - j0);
+            let b = b.as_ptr().offset((j0 * kc) as isize);
+
+            let mut i0 = 0;
+            while i0 < mc {
+                let mr = cmp::min(MR, mc - i0);
+                let a = a.as_ptr().offset((i0 * kc) as isize);
+
+                let ab = micro_kernel(kc, a, b);
+
+                let c = data.offset(i0 as isize * rs + j0 as isize * cs);
+                for i in 0..mr {
+                    for j in 0..nr {
+                        let c = c.offset(i as isize * rs + j as isize * cs);
+
+                        *c = if beta == T::zero() {
+                            alpha * ab[i][j]
+                        } else {
+                            alpha * ab[i][j] + beta * *c
+                        };
+                    }
+                }
+
+                i0 += MR;
+            }
+
+            j0 += NR;
+        }
+    }
+}
+
+/// Computes the `MR`-by-`NR` product of an `A` panel and a `B` panel, both `kc` long
+///
+/// NB The loops have constant bounds, so the accumulator can live in registers
+unsafe fn micro_kernel<T>(kc: usize, mut a: *const T, mut b: *const T) -> [[T; NR]; MR] where
+    T: Scalar,
+{
+    let mut ab = [[T::zero(); NR]; MR];
+
+    for _ in 0..kc {
+        for i in 0..MR {
+            let ai = *a.offset(i as isize);
+
+            for j in 0..NR {
+                ab[i][j] = ab[i][j] + ai * *b.offset(j as isize);
+            }
+        }
+
+        a = a.offset(MR as isize);
+        b = b.offset(NR as isize);
+    }
+
+    ab
+}
+
+/// Matrix product: `&a * &b`
+macro_rules! mul {
+    (@impl $lhs:ty, $rhs:ty) => {
+        impl<'a, 'b, T> Mul<$rhs> for $lhs where T: Scalar {
+            type Output = Box<::Mat<T>>;
+
+            fn mul(self, rhs: $rhs) -> Box<::Mat<T>> {
+                matmul(self.as_strided(), rhs.as_strided())
+            }
+        }
+    };
+    ($lhs:ty) => {
+        mul!(@impl $lhs, &'b ::strided::Mat<T>);
+        mul!(@impl $lhs, &'b ::Mat<T>);
+        mul!(@impl $lhs, &'b ::ColMajor<T>);
+        mul!(@impl $lhs, &'b Box<::Mat<T>>);
+        mul!(@impl $lhs, Box<::Mat<T>>);
+    };
+}
+
+mul!(&'a ::strided::Mat<T>);
+mul!(&'a ::Mat<T>);
+mul!(&'a ::ColMajor<T>);
+mul!(&'a Box<::Mat<T>>);
+mul!(Box<::Mat<T>>);
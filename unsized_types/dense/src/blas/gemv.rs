@@ -0,0 +1,65 @@
+//! General matrix-vector multiplication: `y = alpha * A * x + beta * y`
+
+use std::raw::FatPtr;
+
+use blas::level1::{self, Vector, VectorMut};
+use error::Error;
+use num::Scalar;
+
+/// `y = alpha * A * x + beta * y`
+///
+/// `x` and `y` can be slices, `Row`s or `strided::Col`s. If `beta` is zero, `y` is not read.
+pub fn gemv<T, X: ?Sized, Y: ?Sized>(
+    alpha: T,
+    a: &::strided::Mat<T>,
+    x: &X,
+    beta: T,
+    y: &mut Y,
+) where
+    T: Scalar,
+    X: Vector<T>,
+    Y: VectorMut<T>,
+{
+    let FatPtr { data: a, info } = a.repr();
+    let FatPtr { data: x, info: x_info } = x.vec_repr();
+    let FatPtr { data: y, info: y_info } = y.vec_repr_mut();
+    let (m, n) = (info.nrows, info.ncols);
+    let (rs, cs) = (info.row_stride, info.col_stride);
+    let (incx, incy) = (x_info.stride, y_info.stride);
+
+    if x_info.len != n {
+        panic!("{}", Error::InnerSizeMismatch { lhs: (m, n), rhs: (x_info.len, 1) })
+    }
+
+    level1::assert_same_len(m, y_info.len);
+
+    unsafe {
+        // Walk `A` along its smallest stride
+        if cs.abs() <= rs.abs() {
+            // row by row: `y[i] = alpha * A[i] . x + beta * y[i]`
+            for i in 0..m as isize {
+                let dot = level1::dot_raw(n, a.offset(i * rs), cs, x, incx);
+                let y = y.offset(i * incy);
+
+                *y = if beta == T::zero() {
+                    alpha * dot
+                } else {
+                    alpha * dot + beta * *y
+                };
+            }
+        } else {
+            // column by column: `y = y + (alpha * x[j]) * A[:, j]`
+            for i in 0..m as isize {
+                let y = y.offset(i * incy);
+
+                *y = if beta == T::zero() { T::zero() } else { beta * *y };
+            }
+
+            for j in 0..n as isize {
+                let alpha_xj = alpha * *x.offset(j * incx);
+
+                level1::axpy_raw(m, alpha_xj, a.offset(j * cs), rs, y, incy);
+            }
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//! Vector-vector operations
+
+use std::raw::FatPtr;
+
+use error::Error;
+use num::Scalar;
+
+/// A strided vector: `[T]` (unit stride), `Row<T>` or `strided::Col<T>`
+pub trait Vector<T> {
+    /// Returns the pointer to the first element, the length and the stride of the vector
+    fn vec_repr(&self) -> FatPtr<T, ::strided::col::Info>;
+}
+
+/// A strided vector that can be written to, e.g. the output operand of `axpy` and `gemv`
+pub trait VectorMut<T>: Vector<T> {
+    /// Mutable version of `vec_repr`, the returned pointer can be written through
+    fn vec_repr_mut(&mut self) -> FatPtr<T, ::strided::col::Info>;
+}
+
+impl<T> Vector<T> for [T] {
+    fn vec_repr(&self) -> FatPtr<T, ::strided::col::Info> {
+        FatPtr {
+            data: self.as_ptr() as *mut T,
+            info: ::strided::col::Info {
+                len: self.len(),
+                stride: 1,
+            }
+        }
+    }
+}
+
+impl<T> VectorMut<T> for [T] {
+    fn vec_repr_mut(&mut self) -> FatPtr<T, ::strided::col::Info> {
+        FatPtr {
+            data: self.as_mut_ptr(),
+            info: ::strided::col::Info {
+                len: self.len(),
+                stride: 1,
+            }
+        }
+    }
+}
+
+impl<T> Vector<T> for ::Row<T> {
+    fn vec_repr(&self) -> FatPtr<T, ::strided::col::Info> {
+        self.repr()
+    }
+}
+
+impl<T> VectorMut<T> for ::Row<T> {
+    fn vec_repr_mut(&mut self) -> FatPtr<T, ::strided::col::Info> {
+        self.repr()
+    }
+}
+
+impl<T> Vector<T> for ::strided::Col<T> {
+    fn vec_repr(&self) -> FatPtr<T, ::strided::col::Info> {
+        self.repr()
+    }
+}
+
+impl<T> VectorMut<T> for ::strided::Col<T> {
+    fn vec_repr_mut(&mut self) -> FatPtr<T, ::strided::col::Info> {
+        self.repr()
+    }
+}
+
+/// Panics with `Error::LenMismatch` if the lengths are different
+pub fn assert_same_len(lhs: usize, rhs: usize) {
+    if lhs != rhs {
+        panic!("{}", Error::LenMismatch { lhs: lhs, rhs: rhs })
+    }
+}
+
+/// Returns `x . y`
+pub fn dot<T, X: ?Sized, Y: ?Sized>(x: &X, y: &Y) -> T where
+    T: Scalar,
+    X: Vector<T>,
+    Y: Vector<T>,
+{
+    let FatPtr { data: x, info: x_info } = x.vec_repr();
+    let FatPtr { data: y, info: y_info } = y.vec_repr();
+
+    assert_same_len(x_info.len, y_info.len);
+
+    unsafe {
+        dot_raw(x_info.len, x, x_info.stride, y, y_info.stride)
+    }
+}
+
+/// `y = alpha * x + y`
+pub fn axpy<T, X: ?Sized, Y: ?Sized>(alpha: T, x: &X, y: &mut Y) where
+    T: Scalar,
+    X: Vector<T>,
+    Y: VectorMut<T>,
+{
+    let FatPtr { data: x, info: x_info } = x.vec_repr();
+    let FatPtr { data: y, info: y_info } = y.vec_repr_mut();
+
+    assert_same_len(x_info.len, y_info.len);
+
+    unsafe {
+        axpy_raw(x_info.len, alpha, x, x_info.stride, y, y_info.stride)
+    }
+}
+
+/// `x . y`, where `x` and `y` have `n` elements `incx`/`incy` apart
+pub unsafe fn dot_raw<T>(n: usize, x: *const T, incx: isize, y: *const T, incy: isize) -> T where
+    T: Scalar,
+{
+    let mut sum = T::zero();
+
+    for i in 0..n as isize {
+        sum = sum + *x.offset(i * incx) * *y.offset(i * incy);
+    }
+
+    sum
+}
+
+/// `y = alpha * x + y`, where `x` and `y` have `n` elements `incx`/`incy` apart
+pub unsafe fn axpy_raw<T>(n: usize, alpha: T, x: *const T, incx: isize, y: *mut T, incy: isize)
+    where T: Scalar,
+{
+    for i in 0..n as isize {
+        let y = y.offset(i * incy);
+
+        *y = alpha * *x.offset(i * incx) + *y;
+    }
+}
@@ -0,0 +1,9 @@
+//! BLAS-like routines that work on any strided view
+
+mod gemm;
+mod gemv;
+mod level1;
+
+pub use self::gemm::gemm;
+pub use self::gemv::gemv;
+pub use self::level1::{Vector, VectorMut, assert_same_len, axpy, dot};
@@ -0,0 +1,190 @@
+use std::marker::Unsized;
+use std::ops::{Deref, DerefMut};
+use std::raw::FatPtr;
+use std::{fat_ptr, fmt, mem, ptr, slice};
+
+#[derive(Clone, Copy, Debug)]
+struct Info {
+    pub ncols: usize,
+    pub nrows: usize,
+}
+
+impl<T> ::ColMajor<T> {
+    pub fn reshape<'a>(slice: &'a [T], (nrows, ncols): (usize, usize)) -> &'a ::ColMajor<T> {
+        assert_eq!(slice.len(), nrows * ncols);
+
+        unsafe {
+            &*fat_ptr::new(FatPtr {
+                data: slice.as_ptr() as *mut T,
+                info: Info {
+                    ncols: ncols,
+                    nrows: nrows,
+                }
+            })
+        }
+    }
+
+    pub fn reshape_mut<'a>(
+        slice: &'a mut [T],
+        (nrows, ncols): (usize, usize),
+    ) -> &'a mut ::ColMajor<T> {
+        assert_eq!(slice.len(), nrows * ncols);
+
+        unsafe {
+            &mut *fat_ptr::new(FatPtr {
+                data: slice.as_mut_ptr(),
+                info: Info {
+                    ncols: ncols,
+                    nrows: nrows,
+                }
+            })
+        }
+    }
+
+    /// Creates an owned matrix from a vector of elements stored in column-major order
+    pub fn from_vec(elems: Vec<T>, (nrows, ncols): (usize, usize)) -> Box<::ColMajor<T>> {
+        assert_eq!(elems.len(), nrows * ncols);
+
+        let mut elems = elems.into_boxed_slice();
+        let data = elems.as_mut_ptr();
+        mem::forget(elems);
+
+        unsafe {
+            Box::from_raw(fat_ptr::new(FatPtr {
+                data: data,
+                info: Info {
+                    ncols: ncols,
+                    nrows: nrows,
+                }
+            }))
+        }
+    }
+
+    /// Converts the owned matrix into a vector of elements in column-major order, without copying
+    pub fn into_vec(self: Box<Self>) -> Vec<T> {
+        let FatPtr { data, info } = self.repr();
+        let len = info.nrows * info.ncols;
+
+        mem::forget(self);
+
+        unsafe {
+            Vec::from_raw_parts(data, len, len)
+        }
+    }
+
+    /// Returns the elements in column-major order
+    pub fn as_slice(&self) -> &[T] {
+        let FatPtr { data, info } = self.repr();
+
+        unsafe {
+            slice::from_raw_parts(data, info.nrows * info.ncols)
+        }
+    }
+
+    /// Mutable version of `as_slice`
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        let FatPtr { data, info } = self.repr();
+
+        unsafe {
+            slice::from_raw_parts_mut(data, info.nrows * info.ncols)
+        }
+    }
+
+    /// Returns the transpose, which is the same data interpreted as a row-major matrix
+    pub fn t(&self) -> &::Mat<T> {
+        let info = self.repr().info;
+
+        ::Mat::reshape(self.as_slice(), (info.ncols, info.nrows))
+    }
+
+    /// Mutable version of `t`
+    pub fn t_mut(&mut self) -> &mut ::Mat<T> {
+        let info = self.repr().info;
+
+        ::Mat::reshape_mut(self.as_mut_slice(), (info.ncols, info.nrows))
+    }
+
+    /// Owned version of `t`, no element is copied
+    pub fn into_t(self: Box<Self>) -> Box<::Mat<T>> {
+        let info = self.repr().info;
+
+        ::Mat::from_vec(self.into_vec(), (info.ncols, info.nrows))
+    }
+
+    pub fn repr(&self) -> FatPtr<T, Info> {
+        fat_ptr::repr(self)
+    }
+}
+
+impl<T> Clone for Box<::ColMajor<T>> where T: Clone {
+    fn clone(&self) -> Box<::ColMajor<T>> {
+        let info = self.repr().info;
+
+        ::ColMajor::from_vec(self.as_slice().to_vec(), (info.nrows, info.ncols))
+    }
+}
+
+impl<T> fmt::Debug for ::ColMajor<T> where T: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.deref().fmt(f)
+    }
+}
+
+/// A column-major matrix is also a strided matrix with `row_stride = 1` and `col_stride = nrows`
+impl<T> Deref for ::ColMajor<T> {
+    type Target = ::strided::Mat<T>;
+
+    fn deref(&self) -> &::strided::Mat<T> {
+        let FatPtr { data, info } = self.repr();
+
+        unsafe {
+            &*fat_ptr::new(FatPtr {
+                data: data,
+                info: ::strided::mat::Info {
+                    nrows: info.nrows,
+                    ncols: info.ncols,
+                    row_stride: 1,
+                    col_stride: info.nrows as isize,
+                }
+            })
+        }
+    }
+}
+
+impl<T> DerefMut for ::ColMajor<T> {
+    fn deref_mut(&mut self) -> &mut ::strided::Mat<T> {
+        let FatPtr { data, info } = self.repr();
+
+        unsafe {
+            &mut *fat_ptr::new(FatPtr {
+                data: data,
+                info: ::strided::mat::Info {
+                    nrows: info.nrows,
+                    ncols: info.ncols,
+                    row_stride: 1,
+                    col_stride: info.nrows as isize,
+                }
+            })
+        }
+    }
+}
+
+/// Drops the elements, the memory that holds them is freed by `Box`
+impl<T> Drop for ::ColMajor<T> {
+    fn drop(&mut self) {
+        unsafe {
+            for x in self.as_slice() {
+                ptr::read(x);
+            }
+        }
+    }
+}
+
+impl<T> Unsized for ::ColMajor<T> {
+    type Data = T;
+    type Info = Info;
+
+    fn size_of_val(info: Info) -> usize {
+        info.nrows * info.ncols * mem::size_of::<T>()
+    }
+}
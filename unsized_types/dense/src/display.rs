@@ -0,0 +1,97 @@
+//! Aligned, human-readable `Display` of matrices
+//!
+//! Every column is right-aligned to its widest element and the precision flag (`{:.3}`) is
+//! forwarded to the elements. Axes longer than `THRESHOLD` are elided NumPy-style: only their first
+//! and last `EDGE_ITEMS` elements are shown, with `...` in between.
+
+use std::cmp;
+use std::fmt;
+
+/// Axes longer than this are elided
+const THRESHOLD: usize = 10;
+/// Number of elements shown at each end of an elided axis
+const EDGE_ITEMS: usize = 3;
+
+impl<T> fmt::Display for ::strided::Mat<T> where T: fmt::Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let precision = f.precision();
+        let cols = shown(self.ncols());
+
+        // The formatted elements of the shown rows, `None` stands for the elided rows
+        let rows: Vec<Option<Vec<String>>> = shown(self.nrows()).into_iter().map(|i| i.map(|i| {
+            cols.iter().map(|&j| match j {
+                Some(j) => format_elem(&self[(i, j)], precision),
+                None => "...".to_string(),
+            }).collect()
+        })).collect();
+
+        let mut widths = vec![0; cols.len()];
+        for row in rows.iter().filter_map(|row| row.as_ref()) {
+            for (width, elem) in widths.iter_mut().zip(row) {
+                *width = cmp::max(*width, elem.chars().count());
+            }
+        }
+
+        for (i, row) in rows.iter().enumerate() {
+            if i != 0 {
+                try!(f.write_str("\n"));
+            }
+
+            match *row {
+                None => try!(f.write_str("...")),
+                Some(ref row) => {
+                    try!(f.write_str("["));
+
+                    for (j, (elem, &width)) in row.iter().zip(&widths).enumerate() {
+                        if j != 0 {
+                            try!(f.write_str(", "));
+                        }
+
+                        try!(write!(f, "{:>1$}", elem, width))
+                    }
+
+                    try!(f.write_str("]"))
+                },
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> fmt::Display for ::Mat<T> where T: fmt::Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let m: &::strided::Mat<T> = self;
+
+        fmt::Display::fmt(m, f)
+    }
+}
+
+impl<T> fmt::Display for ::ColMajor<T> where T: fmt::Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let m: &::strided::Mat<T> = self;
+
+        fmt::Display::fmt(m, f)
+    }
+}
+
+/// The indices of an axis of length `len` that are shown, `None` stands for the elided ones
+fn shown(len: usize) -> Vec<Option<usize>> {
+    if len > THRESHOLD {
+        let mut shown: Vec<_> = (0..EDGE_ITEMS).map(Some).collect();
+
+        shown.push(None);
+        shown.extend((len - EDGE_ITEMS..len).map(Some));
+
+        shown
+    } else {
+        (0..len).map(Some).collect()
+    }
+}
+
+fn format_elem<T>(x: &T, precision: Option<usize>) -> String where T: fmt::Display {
+    match precision {
+        Some(precision) => format!("{:.*}", precision, x),
+        None => x.to_string(),
+    }
+}
@@ -0,0 +1,160 @@
+use std::{error, fmt};
+
+/// Error returned by the non-panicking accessors, it reports which bound was violated
+///
+/// Operators that can't return a `Result`, like `+`, panic with this error as the message. The
+/// factorizations in the `linalg` module also report their failures with this type
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Error {
+    /// The index is not less than the length of the `axis`
+    AxisOutOfBounds { axis: usize, index: usize, len: usize },
+    /// The range `start..end` is inverted or doesn't fit in the `axis`
+    AxisRangeOutOfBounds { axis: usize, start: usize, end: usize, len: usize },
+    /// The operands of an element-wise operation can't be broadcast to a common size
+    BroadcastMismatch { lhs: (usize, usize), rhs: (usize, usize) },
+    /// The column index is not less than the number of columns
+    ColOutOfBounds { col: usize, ncols: usize },
+    /// The column range `start..end` is inverted or doesn't fit in `ncols` columns
+    ColRangeOutOfBounds { start: usize, end: usize, ncols: usize },
+    /// The number of columns of `lhs` doesn't match the number of rows of `rhs` in a product
+    InnerSizeMismatch { lhs: (usize, usize), rhs: (usize, usize) },
+    /// The vector operands, or a tensor and its new shape, don't have the same length
+    LenMismatch { lhs: usize, rhs: usize },
+    /// The tensor doesn't have the `expected` number of axes
+    NdimMismatch { expected: usize, found: usize },
+    /// The iterative algorithm didn't converge after `iterations` iterations
+    NoConvergence { iterations: usize },
+    /// The axes of the view are not laid out contiguously enough to be reshaped without copying
+    NotContiguous,
+    /// The matrix has an infinite or NaN element
+    NotFinite,
+    /// The `index` is out of bounds or appears more than once, so the indices are not a
+    /// permutation
+    NotPermutation { index: usize },
+    /// The matrix is not positive definite, the factorization failed at the `pivot` diagonal
+    /// element
+    NotPositiveDefinite { pivot: usize },
+    /// The factorization requires a square matrix
+    NotSquare { size: (usize, usize) },
+    /// The element is not stored by the structured view (e.g. the strictly upper triangle of a
+    /// lower triangular view), so it can't be written
+    OutsideStructure { row: usize, col: usize },
+    /// The row index is not less than the number of rows
+    RowOutOfBounds { row: usize, nrows: usize },
+    /// The row range `start..end` is inverted or doesn't fit in `nrows` rows
+    RowRangeOutOfBounds { start: usize, end: usize, nrows: usize },
+    /// The matrix is singular, no non-zero pivot was found for column `pivot`
+    Singular { pivot: usize },
+    /// The operands of an element-wise operation don't have the same `(nrows, ncols)` size
+    SizeMismatch { lhs: (usize, usize), rhs: (usize, usize) },
+    /// The row interchange `swaps[position] = index` doesn't fit in `len` elements
+    SwapOutOfBounds { position: usize, index: usize, len: usize },
+    /// The tensor would have `ndim` axes, more than `tensor::MAX_NDIM`
+    TooManyAxes { ndim: usize },
+    /// The least squares problem has fewer equations (rows) than unknowns (columns)
+    Underdetermined { size: (usize, usize) },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::AxisOutOfBounds { axis, index, len } => {
+                write!(f, "index {} out of bounds of axis {} (len = {})", index, axis, len)
+            },
+            Error::AxisRangeOutOfBounds { axis, start, end, len } => {
+                write!(f, "range {}..{} out of bounds of axis {} (len = {})", start, end, axis, len)
+            },
+            Error::BroadcastMismatch { lhs, rhs } => {
+                write!(f, "can't broadcast a {}x{} operand with a {}x{} operand",
+                       lhs.0, lhs.1, rhs.0, rhs.1)
+            },
+            Error::ColOutOfBounds { col, ncols } => {
+                write!(f, "column index {} out of bounds (ncols = {})", col, ncols)
+            },
+            Error::ColRangeOutOfBounds { start, end, ncols } => {
+                write!(f, "column range {}..{} out of bounds (ncols = {})", start, end, ncols)
+            },
+            Error::InnerSizeMismatch { lhs, rhs } => {
+                write!(f, "can't multiply a {}x{} matrix by a {}x{} matrix",
+                       lhs.0, lhs.1, rhs.0, rhs.1)
+            },
+            Error::LenMismatch { lhs, rhs } => {
+                write!(f, "length mismatch: {} vs {}", lhs, rhs)
+            },
+            Error::NdimMismatch { expected, found } => {
+                write!(f, "expected {} axes, found {}", expected, found)
+            },
+            Error::NoConvergence { iterations } => {
+                write!(f, "no convergence after {} iterations", iterations)
+            },
+            Error::NotContiguous => {
+                f.write_str("the view can't be reshaped without copying")
+            },
+            Error::NotFinite => {
+                f.write_str("the matrix has an infinite or NaN element")
+            },
+            Error::NotPermutation { index } => {
+                write!(f, "index {} is out of bounds or repeated in the permutation", index)
+            },
+            Error::NotPositiveDefinite { pivot } => {
+                write!(f, "the matrix is not positive definite (pivot {})", pivot)
+            },
+            Error::NotSquare { size } => {
+                write!(f, "expected a square matrix, got a {}x{} matrix", size.0, size.1)
+            },
+            Error::OutsideStructure { row, col } => {
+                write!(f, "element ({}, {}) is outside of the structured view", row, col)
+            },
+            Error::RowOutOfBounds { row, nrows } => {
+                write!(f, "row index {} out of bounds (nrows = {})", row, nrows)
+            },
+            Error::RowRangeOutOfBounds { start, end, nrows } => {
+                write!(f, "row range {}..{} out of bounds (nrows = {})", start, end, nrows)
+            },
+            Error::Singular { pivot } => {
+                write!(f, "the matrix is singular (zero pivot in column {})", pivot)
+            },
+            Error::SizeMismatch { lhs, rhs } => {
+                write!(f, "size mismatch: {}x{} vs {}x{}", lhs.0, lhs.1, rhs.0, rhs.1)
+            },
+            Error::SwapOutOfBounds { position, index, len } => {
+                write!(f, "swap {} <-> {} out of bounds (len = {})", position, index, len)
+            },
+            Error::TooManyAxes { ndim } => {
+                write!(f, "{} axes, at most {} are supported", ndim, ::tensor::MAX_NDIM)
+            },
+            Error::Underdetermined { size } => {
+                write!(f, "underdetermined system: {} equations, {} unknowns", size.0, size.1)
+            },
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn description(&self) -> &str {
+        match *self {
+            Error::AxisOutOfBounds { .. } => "axis index out of bounds",
+            Error::AxisRangeOutOfBounds { .. } => "axis range out of bounds",
+            Error::BroadcastMismatch { .. } => "operands can't be broadcast",
+            Error::ColOutOfBounds { .. } => "column index out of bounds",
+            Error::ColRangeOutOfBounds { .. } => "column range out of bounds",
+            Error::InnerSizeMismatch { .. } => "inner size mismatch",
+            Error::LenMismatch { .. } => "length mismatch",
+            Error::NdimMismatch { .. } => "number of axes mismatch",
+            Error::NoConvergence { .. } => "no convergence",
+            Error::NotContiguous => "view is not contiguous",
+            Error::NotFinite => "non-finite element",
+            Error::NotPermutation { .. } => "not a permutation",
+            Error::NotPositiveDefinite { .. } => "matrix is not positive definite",
+            Error::NotSquare { .. } => "matrix is not square",
+            Error::OutsideStructure { .. } => "element outside of the structured view",
+            Error::RowOutOfBounds { .. } => "row index out of bounds",
+            Error::RowRangeOutOfBounds { .. } => "row range out of bounds",
+            Error::Singular { .. } => "matrix is singular",
+            Error::SizeMismatch { .. } => "size mismatch",
+            Error::SwapOutOfBounds { .. } => "row interchange out of bounds",
+            Error::TooManyAxes { .. } => "too many axes",
+            Error::Underdetermined { .. } => "underdetermined system",
+        }
+    }
+}
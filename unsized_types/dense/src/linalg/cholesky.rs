@@ -0,0 +1,309 @@
+//! Factorizations of symmetric matrices
+
+use std::mem;
+
+use blas::{axpy, dot};
+use error::Error;
+use linalg::check_square;
+use linalg::triangular::{solve_lower, solve_upper};
+use num::Float;
+
+/// Computes the `A = L * L^T` factorization of the symmetric positive definite matrix `a` in
+/// place
+///
+/// Only the lower triangle of `a` is read, and it's overwritten with `L`. The strictly upper
+/// triangle is left untouched.
+///
+/// Returns `Error::NotPositiveDefinite` if `a` is not positive definite, the rows before the
+/// failing pivot hold the corresponding rows of `L` in that case
+pub fn cholesky<T>(a: &mut ::strided::Mat<T>) -> Result<(), Error> where T: Float {
+    let n = try!(check_square(a));
+
+    for i in 0..n {
+        for j in 0..i + 1 {
+            let s = a[(i, j)] - dot(&a[(i, ..j)], &a[(j, ..j)]);
+
+            if i == j {
+                // `!(s > 0)` also catches NaNs
+                if !(s > T::zero()) {
+                    return Err(Error::NotPositiveDefinite { pivot: i })
+                }
+
+                a[(i, i)] = s.sqrt();
+            } else {
+                a[(i, j)] = s / a[(j, j)];
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Solves `A * X = B` in place, given the `l` Cholesky factor of `A`
+///
+/// Each column of `b` is a right-hand side, `b` is overwritten with the solution
+pub fn cholesky_solve<T>(l: &::strided::Mat<T>, b: &mut ::strided::Mat<T>) where T: Float {
+    solve_lower(l, false, b);
+    solve_upper(l.t(), false, b);
+}
+
+/// A diagonal block of the `ldlt` factorization, with the interchange done before it
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LdltPivot {
+    /// `1 x 1` block at `(k, k)`, the row and column `k` were swapped with the given one
+    One(usize),
+    /// `2 x 2` block at `(k..k + 2, k..k + 2)`, the row and column `k + 1` were swapped with the
+    /// given one
+    Two(usize),
+}
+
+impl LdltPivot {
+    /// Number of rows of the block
+    fn size(&self) -> usize {
+        match *self {
+            LdltPivot::One(_) => 1,
+            LdltPivot::Two(_) => 2,
+        }
+    }
+}
+
+/// Computes the `P * A * P^T = L * D * L^T` factorization of the symmetric matrix `a` in place,
+/// using Bunch-Kaufman pivoting
+///
+/// Unlike `cholesky`, `a` may be indefinite: `D` is block diagonal with `1 x 1` and `2 x 2`
+/// blocks, so e.g. `[[0, 1], [1, 0]]` is factorized with a single `2 x 2` block. Only the lower
+/// triangle of `a` is read; it's overwritten with the lower triangle of the blocks of `D` and
+/// with `L` below them (its diagonal is all ones and is not stored). Like LAPACK's `sytrf`, the
+/// rows of `L` are stored before the interchanges of the later steps, so `ld` is only meant to be
+/// used with `ldlt_solve`. The blocks are returned in order, with their interchanges.
+///
+/// Returns `Error::Singular` if a whole column of the remaining submatrix, or the determinant of a
+/// `2 x 2` block divided by its off-diagonal element, is not larger than `n * eps * max|A|` in
+/// magnitude, i.e. if `a` is singular to working precision. `a` is left partially factorized in
+/// that case.
+pub fn ldlt<T>(a: &mut ::strided::Mat<T>) -> Result<Vec<LdltPivot>, Error> where T: Float {
+    let n = try!(check_square(a));
+    let mut pivots = vec![];
+
+    // Growth factor bound of Bunch and Kaufman
+    let alpha = (T::one() + T::from_usize(17).sqrt()) / T::from_usize(8);
+    let mut max = T::zero();
+    for i in 0..n {
+        max = a[(i, ..i + 1)].iter().fold(max, |max, &x| if x.abs() > max { x.abs() } else { max });
+    }
+    let tol = T::from_usize(n) * T::epsilon() * max;
+
+    let mut k = 0;
+    while k < n {
+        let akk = a[(k, k)].abs();
+        // Largest off-diagonal element of the column `k`
+        let (imax, colmax) = largest((k + 1..n).map(|i| (i, a[(i, k)].abs())));
+
+        if akk <= tol && colmax <= tol {
+            return Err(Error::Singular { pivot: k })
+        }
+
+        let pivot = if akk >= alpha * colmax {
+            LdltPivot::One(k)
+        } else {
+            // Largest off-diagonal element of the row/column `imax` of the remaining submatrix
+            let (_, rowmax) = largest((k..imax).map(|j| (j, a[(imax, j)].abs()))
+                .chain((imax + 1..n).map(|i| (i, a[(i, imax)].abs()))));
+
+            if akk * rowmax >= alpha * colmax * colmax {
+                LdltPivot::One(k)
+            } else if a[(imax, imax)].abs() >= alpha * rowmax {
+                LdltPivot::One(imax)
+            } else {
+                LdltPivot::Two(imax)
+            }
+        };
+
+        match pivot {
+            LdltPivot::One(p) => {
+                interchange(a, k, k, p);
+
+                // `A22 = A22 - a21 * a21^T / d` and `l21 = a21 / d`
+                let d = a[(k, k)];
+                for j in k + 1..n {
+                    let l = a[(j, k)] / d;
+
+                    for i in j..n {
+                        a[(i, j)] = a[(i, j)] - a[(i, k)] * l;
+                    }
+                }
+
+                for i in k + 1..n {
+                    a[(i, k)] = a[(i, k)] / d;
+                }
+            },
+            LdltPivot::Two(p) => {
+                interchange(a, k, k + 1, p);
+
+                // `A22 = A22 - A21 * D^-1 * A21^T` and `L21 = A21 * D^-1`, where `D^-1` is
+                // computed as in LAPACK's `sytf2` to avoid overflow
+                let d21 = a[(k + 1, k)];
+                let d11 = a[(k, k)] / d21;
+                let d22 = a[(k + 1, k + 1)] / d21;
+                let r = d11 * d22 - T::one();
+
+                // `d21 * r` is `det(D) / d21`; `!(_ > tol)` also catches NaNs
+                if !((d21 * r).abs() > tol) {
+                    return Err(Error::Singular { pivot: k })
+                }
+
+                let d21 = T::one() / r / d21;
+
+                for j in k + 2..n {
+                    let wk = d21 * (d22 * a[(j, k)] - a[(j, k + 1)]);
+                    let wk1 = d21 * (d11 * a[(j, k + 1)] - a[(j, k)]);
+
+                    for i in j..n {
+                        a[(i, j)] = a[(i, j)] - (a[(i, k)] * wk + a[(i, k + 1)] * wk1);
+                    }
+
+                    a[(j, k)] = wk;
+                    a[(j, k + 1)] = wk1;
+                }
+            },
+        }
+
+        k += pivot.size();
+        pivots.push(pivot);
+    }
+
+    Ok(pivots)
+}
+
+/// Solves `A * X = B` in place, given the `ld` factorization of `A` and its `pivots` computed by
+/// `ldlt`
+///
+/// Each column of `b` is a right-hand side, `b` is overwritten with the solution
+pub fn ldlt_solve<T>(ld: &::strided::Mat<T>, pivots: &[LdltPivot], b: &mut ::strided::Mat<T>) where
+    T: Float,
+{
+    if ld.ncols() != b.nrows() {
+        panic!("{}", Error::InnerSizeMismatch { lhs: ld.size(), rhs: b.size() })
+    }
+
+    let mut starts = Vec::with_capacity(pivots.len());
+    let mut k = 0;
+    for pivot in pivots {
+        starts.push(k);
+        k += pivot.size();
+    }
+
+    // `B = D^-1 * L^-1 * P * B`
+    for (&k, &pivot) in starts.iter().zip(pivots) {
+        let (top, bottom) = b.split_at_row_mut(k + pivot.size());
+
+        match pivot {
+            LdltPivot::One(p) => {
+                swap_rows(top, bottom, k, p);
+
+                for (i, row) in bottom.rows_mut().enumerate() {
+                    axpy(-ld[(k + 1 + i, k)], &top[k], row);
+                }
+
+                let d = ld[(k, k)];
+                for x in top[k].iter_mut() {
+                    *x = *x / d;
+                }
+            },
+            LdltPivot::Two(p) => {
+                swap_rows(top, bottom, k + 1, p);
+
+                for (i, row) in bottom.rows_mut().enumerate() {
+                    axpy(-ld[(k + 2 + i, k)], &top[k], row);
+                    axpy(-ld[(k + 2 + i, k + 1)], &top[k + 1], row);
+                }
+
+                let d21 = ld[(k + 1, k)];
+                let d11 = ld[(k, k)] / d21;
+                let d22 = ld[(k + 1, k + 1)] / d21;
+                let denom = d11 * d22 - T::one();
+
+                for j in 0..top.ncols() {
+                    let x1 = top[(k, j)] / d21;
+                    let x2 = top[(k + 1, j)] / d21;
+
+                    top[(k, j)] = (d22 * x1 - x2) / denom;
+                    top[(k + 1, j)] = (d11 * x2 - x1) / denom;
+                }
+            },
+        }
+    }
+
+    // `B = P^T * L^-T * B`
+    for (&k, &pivot) in starts.iter().zip(pivots).rev() {
+        let (top, bottom) = b.split_at_row_mut(k + pivot.size());
+
+        match pivot {
+            LdltPivot::One(p) => {
+                for (i, row) in bottom.rows().enumerate() {
+                    axpy(-ld[(k + 1 + i, k)], row, &mut top[k]);
+                }
+
+                swap_rows(top, bottom, k, p);
+            },
+            LdltPivot::Two(p) => {
+                for (i, row) in bottom.rows().enumerate() {
+                    axpy(-ld[(k + 2 + i, k)], row, &mut top[k]);
+                    axpy(-ld[(k + 2 + i, k + 1)], row, &mut top[k + 1]);
+                }
+
+                swap_rows(top, bottom, k + 1, p);
+            },
+        }
+    }
+}
+
+/// Swaps the row and column `i` with the row and column `p >= i` of the symmetric submatrix
+/// `a[(k.., k..)]`, of which only the lower triangle is stored
+///
+/// The rows of the first `k` columns are not swapped
+fn interchange<T>(a: &mut ::strided::Mat<T>, k: usize, i: usize, p: usize) where T: Copy {
+    if p == i {
+        return
+    }
+
+    let n = a.nrows();
+    let swap = |a: &mut ::strided::Mat<T>, x: (usize, usize), y: (usize, usize)| {
+        let t = a[x];
+        a[x] = a[y];
+        a[y] = t;
+    };
+
+    for j in p + 1..n {
+        swap(a, (j, i), (j, p));
+    }
+
+    for j in i + 1..p {
+        swap(a, (j, i), (p, j));
+    }
+
+    swap(a, (i, i), (p, p));
+
+    // The off-diagonal element of a `2 x 2` block
+    if i != k {
+        swap(a, (i, k), (p, k));
+    }
+}
+
+/// Swaps the row `i` of `top` with the row `p >= i` of `top` above `bottom`
+fn swap_rows<T>(top: &mut ::strided::Mat<T>, bottom: &mut ::strided::Mat<T>, i: usize, p: usize) {
+    let n = top.nrows();
+
+    if p < n {
+        top.swap_rows(i, p)
+    } else {
+        for (x, y) in top[i].iter_mut().zip(bottom[p - n].iter_mut()) {
+            mem::swap(x, y);
+        }
+    }
+}
+
+/// The position and value of the largest of the `(position, value)` pairs, `(0, 0)` if empty
+fn largest<T, I>(elems: I) -> (usize, T) where I: Iterator<Item=(usize, T)>, T: Float {
+    elems.fold((0, T::zero()), |max, (i, x)| if x > max.1 { (i, x) } else { max })
+}
@@ -0,0 +1,303 @@
+//! Symmetric eigenvalue decomposition
+
+use std::cmp;
+
+use error::Error;
+use linalg::check_square;
+use num::Float;
+
+/// Maximum number of implicit QL iterations spent on each eigenvalue
+const MAX_ITER: usize = 30;
+
+/// Eigenvalues and, optionally, eigenvectors of a symmetric matrix
+pub struct Eigh<T> {
+    /// The eigenvalues, in ascending order
+    pub values: Vec<T>,
+    /// The orthonormal eigenvectors, the column `j` is associated to `values[j]`
+    pub vectors: Option<Box<::Mat<T>>>,
+}
+
+/// Computes the eigenvalues and, if `vectors` is true, the eigenvectors of the symmetric matrix
+/// `a`
+///
+/// Only the lower triangle of `a` is read. `a` is reduced to tridiagonal form with Householder
+/// reflectors, then the tridiagonal matrix is diagonalized with the implicit QL algorithm.
+///
+/// Returns `Error::NoConvergence` if an eigenvalue needs more than 30 iterations
+pub fn eigh<T>(a: &::strided::Mat<T>, vectors: bool) -> Result<Eigh<T>, Error> where T: Float {
+    let n = try!(check_square(a));
+
+    if n == 0 {
+        let v = if vectors { Some(::Mat::eye(0)) } else { None };
+
+        return Ok(Eigh { values: vec![], vectors: v })
+    }
+
+    let mut v = ::Mat::from_fn((n, n), |i, j| a[(cmp::max(i, j), cmp::min(i, j))]);
+    let mut d = vec![T::zero(); n];
+    let mut e = vec![T::zero(); n];
+
+    tridiagonalize(&mut v, &mut d, &mut e, vectors);
+    try!(tql2(&mut v, &mut d, &mut e, vectors));
+
+    Ok(Eigh { values: d, vectors: if vectors { Some(v) } else { None } })
+}
+
+/// Householder reduction of the symmetric matrix `v` to tridiagonal form
+///
+/// On return, `d` holds the diagonal, `e[1..]` the subdiagonal and, if `vectors` is true, `v` the
+/// orthogonal transformation. Only the lower triangle of `v` is read on entry.
+fn tridiagonalize<T>(v: &mut ::strided::Mat<T>, d: &mut [T], e: &mut [T], vectors: bool) where
+    T: Float,
+{
+    let n = d.len();
+
+    for j in 0..n {
+        d[j] = v[(n - 1, j)];
+    }
+
+    for i in (1..n).rev() {
+        // Scale to avoid under/overflow
+        let mut scale = T::zero();
+        let mut h = T::zero();
+        for k in 0..i {
+            scale = scale + d[k].abs();
+        }
+
+        if scale == T::zero() {
+            e[i] = d[i - 1];
+
+            for j in 0..i {
+                d[j] = v[(i - 1, j)];
+                v[(i, j)] = T::zero();
+                v[(j, i)] = T::zero();
+            }
+        } else {
+            // Generate the Householder vector
+            for k in 0..i {
+                d[k] = d[k] / scale;
+                h = h + d[k] * d[k];
+            }
+
+            let mut f = d[i - 1];
+            let mut g = h.sqrt();
+            if f > T::zero() {
+                g = -g;
+            }
+
+            e[i] = scale * g;
+            h = h - f * g;
+            d[i - 1] = f - g;
+
+            for j in 0..i {
+                e[j] = T::zero();
+            }
+
+            // Apply the similarity transformation to the remaining columns
+            for j in 0..i {
+                f = d[j];
+                v[(j, i)] = f;
+                g = e[j] + v[(j, j)] * f;
+
+                for k in j + 1..i {
+                    g = g + v[(k, j)] * d[k];
+                    e[k] = e[k] + v[(k, j)] * f;
+                }
+
+                e[j] = g;
+            }
+
+            f = T::zero();
+            for j in 0..i {
+                e[j] = e[j] / h;
+                f = f + e[j] * d[j];
+            }
+
+            let hh = f / (h + h);
+            for j in 0..i {
+                e[j] = e[j] - hh * d[j];
+            }
+
+            for j in 0..i {
+                f = d[j];
+                g = e[j];
+
+                for k in j..i {
+                    v[(k, j)] = v[(k, j)] - (f * e[k] + g * d[k]);
+                }
+
+                d[j] = v[(i - 1, j)];
+                v[(i, j)] = T::zero();
+            }
+        }
+
+        d[i] = h;
+    }
+
+    e[0] = T::zero();
+
+    if !vectors {
+        // The diagonal is all that's left to read, `v` is garbage from here on
+        for j in 0..n {
+            d[j] = v[(j, j)];
+        }
+
+        return
+    }
+
+    // Accumulate the transformations
+    for i in 0..n - 1 {
+        v[(n - 1, i)] = v[(i, i)];
+        v[(i, i)] = T::one();
+
+        let h = d[i + 1];
+        if h != T::zero() {
+            for k in 0..i + 1 {
+                d[k] = v[(k, i + 1)] / h;
+            }
+
+            for j in 0..i + 1 {
+                let mut g = T::zero();
+                for k in 0..i + 1 {
+                    g = g + v[(k, i + 1)] * v[(k, j)];
+                }
+
+                for k in 0..i + 1 {
+                    v[(k, j)] = v[(k, j)] - g * d[k];
+                }
+            }
+        }
+
+        for k in 0..i + 1 {
+            v[(k, i + 1)] = T::zero();
+        }
+    }
+
+    for j in 0..n {
+        d[j] = v[(n - 1, j)];
+        v[(n - 1, j)] = T::zero();
+    }
+
+    v[(n - 1, n - 1)] = T::one();
+}
+
+/// Diagonalizes the tridiagonal matrix produced by `tridiagonalize` with the implicit QL
+/// algorithm, then sorts the eigenvalues
+///
+/// The rotations are accumulated in `v` only if `vectors` is true
+fn tql2<T>(
+    v: &mut ::strided::Mat<T>,
+    d: &mut [T],
+    e: &mut [T],
+    vectors: bool,
+) -> Result<(), Error> where
+    T: Float,
+{
+    let n = d.len();
+    let two = T::one() + T::one();
+
+    for i in 1..n {
+        e[i - 1] = e[i];
+    }
+    e[n - 1] = T::zero();
+
+    let mut f = T::zero();
+    let mut tst1 = T::zero();
+    for l in 0..n {
+        // Find a small subdiagonal element
+        let t = d[l].abs() + e[l].abs();
+        if t > tst1 {
+            tst1 = t;
+        }
+
+        let mut m = l;
+        while m < n - 1 && e[m].abs() > T::epsilon() * tst1 {
+            m += 1;
+        }
+
+        // If `m == l`, `d[l]` is already an eigenvalue
+        let mut iter = 0;
+        while m > l && e[l].abs() > T::epsilon() * tst1 {
+            iter += 1;
+            if iter > MAX_ITER {
+                return Err(Error::NoConvergence { iterations: MAX_ITER })
+            }
+
+            // Compute the implicit shift
+            let mut g = d[l];
+            let mut p = (d[l + 1] - g) / (two * e[l]);
+            let mut r = p.hypot(T::one());
+            if p < T::zero() {
+                r = -r;
+            }
+
+            d[l] = e[l] / (p + r);
+            d[l + 1] = e[l] * (p + r);
+
+            let dl1 = d[l + 1];
+            let mut h = g - d[l];
+            for i in l + 2..n {
+                d[i] = d[i] - h;
+            }
+            f = f + h;
+
+            // Implicit QL transformation
+            p = d[m];
+            let mut c = T::one();
+            let mut c2 = c;
+            let mut c3 = c;
+            let el1 = e[l + 1];
+            let mut s = T::zero();
+            let mut s2 = T::zero();
+            for i in (l..m).rev() {
+                c3 = c2;
+                c2 = c;
+                s2 = s;
+                g = c * e[i];
+                h = c * p;
+                r = p.hypot(e[i]);
+                e[i + 1] = s * r;
+                s = e[i] / r;
+                c = p / r;
+                p = c * d[i] - s * g;
+                d[i + 1] = h + s * (c * g + s * d[i]);
+
+                if vectors {
+                    for k in 0..n {
+                        let h = v[(k, i + 1)];
+
+                        v[(k, i + 1)] = s * v[(k, i)] + c * h;
+                        v[(k, i)] = c * v[(k, i)] - s * h;
+                    }
+                }
+            }
+
+            p = -s * s2 * c3 * el1 * e[l] / dl1;
+            e[l] = s * p;
+            d[l] = c * p;
+        }
+
+        d[l] = d[l] + f;
+        e[l] = T::zero();
+    }
+
+    // Selection sort, in ascending order
+    for i in 0..n - 1 {
+        let mut k = i;
+        for j in i + 1..n {
+            if d[j] < d[k] {
+                k = j;
+            }
+        }
+
+        if k != i {
+            d.swap(i, k);
+
+            if vectors {
+                v.t_mut().swap_rows(i, k);
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,178 @@
+//! LU factorization with partial pivoting
+
+use std::cmp;
+
+use blas::gemm;
+use error::Error;
+use linalg::check_square;
+use linalg::triangular::{solve_lower, solve_upper};
+use num::Float;
+
+/// Width of the panels that are factorized with the unblocked algorithm
+const NB: usize = 32;
+
+/// Computes the `P * A = L * U` factorization of the square matrix `a` in place
+///
+/// On success, the strictly lower triangle of `a` holds `L` (its diagonal is all ones and is not
+/// stored) and the upper triangle holds `U`. The permutation `P` is returned as a sequence of
+/// row swaps: at step `k`, the row `k` was swapped with the row `pivots[k]`.
+///
+/// Returns `Error::Singular` if a column has no pivot larger than `n * eps * max|A|` in magnitude,
+/// i.e. if `a` is singular to working precision. `a` is left partially factorized in that case.
+/// Returns `Error::NotFinite` up front if `a` has an infinite or NaN element, which would otherwise
+/// come out of the elimination as NaNs. `solve`, `det` and `inverse` inherit these checks.
+pub fn lu<T>(a: &mut ::strided::Mat<T>) -> Result<Vec<usize>, Error> where T: Float {
+    let n = try!(check_square(a));
+    let mut pivots = Vec::with_capacity(n);
+
+    if !a.fold(true, |finite, &x| finite && x.is_finite()) {
+        return Err(Error::NotFinite)
+    }
+
+    // Pivots below this are roundoff left by the elimination, not genuine non-zeros
+    let max = a.fold(T::zero(), |max, &x| if x.abs() > max { x.abs() } else { max });
+    let tol = T::from_usize(n) * T::epsilon() * max;
+
+    let mut k = 0;
+    while k < n {
+        let nb = cmp::min(NB, n - k);
+
+        try!(lu_panel(&mut a[(k.., k..k + nb)], k, tol, &mut pivots));
+
+        // Apply the swaps of the panel to the columns on both sides of it
+        for i in k..k + nb {
+            a[(.., ..k)].swap_rows(i, pivots[i]);
+            a[(.., k + nb..)].swap_rows(i, pivots[i]);
+        }
+
+        // `A12 = L11^-1 * A12` and `A22 = A22 - A21 * A12`
+        let (a11, a12, a21, a22) = a[(k.., k..)].split_quad_mut((nb, nb));
+
+        solve_lower(a11, true, a12);
+        gemm(-T::one(), a21, a12, T::one(), a22);
+
+        k += NB;
+    }
+
+    Ok(pivots)
+}
+
+/// Solves `A * X = B` in place, given the `lu` factorization of `A` and its `pivots`
+///
+/// Each column of `b` is a right-hand side, `b` is overwritten with the solution
+pub fn lu_solve<T>(lu: &::strided::Mat<T>, pivots: &[usize], b: &mut ::strided::Mat<T>) where
+    T: Float,
+{
+    if lu.ncols() != b.nrows() {
+        panic!("{}", Error::InnerSizeMismatch { lhs: lu.size(), rhs: b.size() })
+    }
+
+    for (i, &p) in pivots.iter().enumerate() {
+        b.swap_rows(i, p);
+    }
+
+    solve_lower(lu, true, b);
+    solve_upper(lu, false, b);
+}
+
+/// Solves `A * X = B`, `b` is overwritten with the solution and `a` is left untouched
+///
+/// Each column of `b` is a right-hand side. Returns `Error::Singular` if `a` is singular to
+/// working precision (see `lu`)
+pub fn solve<T>(a: &::strided::Mat<T>, b: &mut ::strided::Mat<T>) -> Result<(), Error> where
+    T: Float,
+{
+    if a.ncols() != b.nrows() {
+        return Err(Error::InnerSizeMismatch { lhs: a.size(), rhs: b.size() })
+    }
+
+    let mut f = ::Mat::from_fn(a.size(), |i, j| a[(i, j)]);
+    let pivots = try!(lu(&mut f));
+
+    lu_solve(&f, &pivots, b);
+
+    Ok(())
+}
+
+/// Returns the determinant of `a`, which is zero if `a` is singular to working precision (see
+/// `lu`)
+pub fn det<T>(a: &::strided::Mat<T>) -> Result<T, Error> where T: Float {
+    let mut f = ::Mat::from_fn(a.size(), |i, j| a[(i, j)]);
+
+    match lu(&mut f) {
+        Ok(pivots) => {
+            let mut det = T::one();
+
+            for (i, &p) in pivots.iter().enumerate() {
+                det = det * f[(i, i)];
+
+                if i != p {
+                    det = -det;
+                }
+            }
+
+            Ok(det)
+        },
+        Err(Error::Singular { .. }) => Ok(T::zero()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns the inverse of `a`, or `Error::Singular` if `a` is singular to working precision (see
+/// `lu`)
+pub fn inverse<T>(a: &::strided::Mat<T>) -> Result<Box<::Mat<T>>, Error> where T: Float {
+    let mut f = ::Mat::from_fn(a.size(), |i, j| a[(i, j)]);
+    let pivots = try!(lu(&mut f));
+    let mut inv = ::Mat::eye(f.nrows());
+
+    lu_solve(&f, &pivots, &mut inv);
+
+    Ok(inv)
+}
+
+/// Unblocked factorization of the `p` panel, whose top-left corner is the `(offset, offset)`
+/// element of the full matrix. Pivots whose magnitude is not larger than `tol` are zero.
+fn lu_panel<T>(
+    p: &mut ::strided::Mat<T>,
+    offset: usize,
+    tol: T,
+    pivots: &mut Vec<usize>,
+) -> Result<(), Error> where
+    T: Float,
+{
+    let (m, n) = p.size();
+
+    for j in 0..n {
+        // The element with the largest magnitude is the pivot
+        let mut piv = j;
+        for i in j + 1..m {
+            if p[(i, j)].abs() > p[(piv, j)].abs() {
+                piv = i;
+            }
+        }
+
+        let pivot = p[(piv, j)];
+
+        // `!(_ > tol)` also catches NaNs
+        if !(pivot.abs() > tol) {
+            return Err(Error::Singular { pivot: offset + j })
+        }
+
+        pivots.push(offset + piv);
+        p.swap_rows(j, piv);
+
+        for i in j + 1..m {
+            let l = p[(i, j)] / pivot;
+
+            p[(i, j)] = l;
+
+            for c in j + 1..n {
+                let u = p[(j, c)];
+
+                p[(i, c)] = p[(i, c)] - l * u;
+            }
+        }
+    }
+
+    Ok(())
+}
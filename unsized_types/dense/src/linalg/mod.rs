@@ -0,0 +1,28 @@
+//! Matrix factorizations and the solvers built on top of them
+
+mod cholesky;
+mod eig;
+mod lu;
+mod qr;
+mod svd;
+mod triangular;
+
+pub use self::cholesky::{LdltPivot, cholesky, cholesky_solve, ldlt, ldlt_solve};
+pub use self::eig::{Eigh, eigh};
+pub use self::lu::{det, inverse, lu, lu_solve, solve};
+pub use self::qr::{lstsq, qr, qr_apply_q, qr_apply_qt, qr_pivoted, qr_q, qr_r};
+pub use self::svd::{Svd, svd};
+pub use self::triangular::{solve_lower, solve_upper};
+
+use error::Error;
+
+/// Returns the order of `a`, or `Error::NotSquare` if `a` is not square
+fn check_square<T>(a: &::strided::Mat<T>) -> Result<usize, Error> {
+    let (nrows, ncols) = a.size();
+
+    if nrows == ncols {
+        Ok(nrows)
+    } else {
+        Err(Error::NotSquare { size: (nrows, ncols) })
+    }
+}
@@ -0,0 +1,226 @@
+//! Householder QR factorization and least squares
+
+use std::cmp;
+
+use blas::{Vector, axpy, dot};
+use error::Error;
+use linalg::triangular::solve_upper;
+use num::Float;
+
+/// Computes the `A = Q * R` factorization of the `m x n` matrix `a` in place
+///
+/// On return, the upper triangle of `a` holds `R`. `Q` is stored compactly as the product of
+/// `k = min(m, n)` reflectors `H_j = I - tau[j] * v_j * v_j^T`: the returned vector holds the
+/// `tau` factors and the column `j` of `a`, below the diagonal, holds `v_j` (whose first element
+/// is one and is not stored).
+///
+/// Use `qr_apply_q`/`qr_apply_qt` to multiply by `Q` and `qr_q`/`qr_r` to form the factors
+pub fn qr<T>(a: &mut ::strided::Mat<T>) -> Vec<T> where T: Float {
+    let k = cmp::min(a.nrows(), a.ncols());
+    let mut tau = Vec::with_capacity(k);
+
+    for j in 0..k {
+        tau.push(qr_step(a, j));
+    }
+
+    tau
+}
+
+/// Column pivoted version of `qr`: `A * P = Q * R`
+///
+/// At each step, the remaining column with the largest norm is moved to the front, which makes
+/// the diagonal of `R` non-increasing in magnitude and reveals the numerical rank of `a`.
+///
+/// Returns the `tau` factors and the permutation: the column `j` of `A * P` is the column
+/// `perm[j]` of `A`
+pub fn qr_pivoted<T>(a: &mut ::strided::Mat<T>) -> (Vec<T>, Vec<usize>) where T: Float {
+    let (m, n) = a.size();
+    let k = cmp::min(m, n);
+    let mut tau = Vec::with_capacity(k);
+    let mut perm = (0..n).collect::<Vec<_>>();
+
+    for j in 0..k {
+        let mut p = j;
+        let mut max = T::zero();
+        for c in j..n {
+            let col = &a[(j.., c)];
+            let norm2 = dot(col, col);
+
+            if norm2 > max {
+                p = c;
+                max = norm2;
+            }
+        }
+
+        a.t_mut().swap_rows(j, p);
+        perm.swap(j, p);
+
+        tau.push(qr_step(a, j));
+    }
+
+    (tau, perm)
+}
+
+/// `B = Q * B`, given the compact `qr` factorization computed by `qr` and its `tau` factors
+pub fn qr_apply_q<T>(qr: &::strided::Mat<T>, tau: &[T], b: &mut ::strided::Mat<T>) where
+    T: Float,
+{
+    check_rows(qr, b);
+
+    for (j, &tau) in tau.iter().enumerate().rev() {
+        reflect(&qr[(j + 1.., j)], tau, &mut b[(j.., ..)]);
+    }
+}
+
+/// `B = Q^T * B`, given the compact `qr` factorization computed by `qr` and its `tau` factors
+pub fn qr_apply_qt<T>(qr: &::strided::Mat<T>, tau: &[T], b: &mut ::strided::Mat<T>) where
+    T: Float,
+{
+    check_rows(qr, b);
+
+    for (j, &tau) in tau.iter().enumerate() {
+        reflect(&qr[(j + 1.., j)], tau, &mut b[(j.., ..)]);
+    }
+}
+
+/// Forms the `m x k` matrix made of the first `k = min(m, n)` columns of `Q`
+pub fn qr_q<T>(qr: &::strided::Mat<T>, tau: &[T]) -> Box<::Mat<T>> where T: Float {
+    let m = qr.nrows();
+    let k = cmp::min(m, qr.ncols());
+    let mut q = ::Mat::from_fn((m, k), |i, j| if i == j { T::one() } else { T::zero() });
+
+    qr_apply_q(qr, tau, &mut q);
+
+    q
+}
+
+/// Copies the `k x n` upper trapezoidal factor `R`, where `k = min(m, n)`
+pub fn qr_r<T>(qr: &::strided::Mat<T>) -> Box<::Mat<T>> where T: Float {
+    let (m, n) = qr.size();
+
+    ::Mat::from_fn((cmp::min(m, n), n), |i, j| if i <= j { qr[(i, j)] } else { T::zero() })
+}
+
+/// Returns the `X` that minimizes the norm of each column of `A * X - B`, and the norms of
+/// these residuals
+///
+/// `a` must have at least as many rows as columns and full column rank. The rank is decided with
+/// the column pivoted factorization (`qr_pivoted`): if a diagonal element of `R` is not larger
+/// than `m * eps * |R[0, 0]|` in magnitude, `Error::Singular` is returned with the numerical rank
+/// of `a` as the `pivot`. `a` and `b` are left untouched
+pub fn lstsq<T>(
+    a: &::strided::Mat<T>,
+    b: &::strided::Mat<T>,
+) -> Result<(Box<::Mat<T>>, Vec<T>), Error> where
+    T: Float,
+{
+    let (m, n) = a.size();
+
+    if m < n {
+        return Err(Error::Underdetermined { size: (m, n) })
+    }
+
+    if b.nrows() != m {
+        return Err(Error::SizeMismatch { lhs: a.size(), rhs: b.size() })
+    }
+
+    let mut f = ::Mat::from_fn(a.size(), |i, j| a[(i, j)]);
+    let (tau, perm) = qr_pivoted(&mut f);
+
+    // The diagonal of `R` is non-increasing in magnitude, the elements below this are roundoff
+    let tol = if n == 0 { T::zero() } else { T::from_usize(m) * T::epsilon() * f[(0, 0)].abs() };
+    for i in 0..n {
+        if f[(i, i)].abs() <= tol {
+            return Err(Error::Singular { pivot: i })
+        }
+    }
+
+    let mut c = ::Mat::from_fn(b.size(), |i, j| b[(i, j)]);
+    qr_apply_qt(&f, &tau, &mut c);
+
+    // The last `m - n` rows of `Q^T * B` are the part of `B` that `A * X` can't reach
+    let residuals = c[(n.., ..)].cols().map(|col| norm(col)).collect();
+    let mut y = ::Mat::from_fn((n, b.ncols()), |i, j| c[(i, j)]);
+    solve_upper(&f[(..n, ..)], false, &mut y);
+
+    // `A * P * Y = B`, so `X = P * Y`: the row `j` of `Y` is the row `perm[j]` of `X`
+    let mut x = ::Mat::zeros(y.size());
+    x.scatter_rows(&perm, &y);
+
+    Ok((x, residuals))
+}
+
+/// Reduces the column `j` of `a` with a reflector that's applied to the trailing columns,
+/// returns its `tau` factor
+fn qr_step<T>(a: &mut ::strided::Mat<T>, j: usize) -> T where T: Float {
+    let (col, rest) = a[(j.., j..)].split_at_col_mut(1);
+    let tau = householder(col);
+
+    reflect(&col[(1.., 0)], tau, rest);
+
+    tau
+}
+
+/// Computes the reflector `H = I - tau * v * v^T` that maps the `x` column to `[beta, 0, ..]`
+///
+/// `x` is overwritten with `beta` followed by the tail of `v`, returns `tau`
+fn householder<T>(x: &mut ::strided::Mat<T>) -> T where T: Float {
+    let (head, tail) = x.split_at_row_mut(1);
+    let tail = &mut tail[(.., 0)];
+    let tail_norm = norm(tail);
+
+    if tail_norm == T::zero() {
+        // Already reduced, `H` is the identity
+        return T::zero()
+    }
+
+    let alpha = head[(0, 0)];
+    // `beta` has the opposite sign of `alpha` to avoid cancellation in `alpha - beta`
+    let mut beta = (alpha * alpha + tail_norm * tail_norm).sqrt();
+    if alpha > T::zero() {
+        beta = -beta;
+    }
+
+    let s = alpha - beta;
+    for x in tail.iter_mut() {
+        *x = *x / s;
+    }
+
+    head[(0, 0)] = beta;
+
+    (beta - alpha) / beta
+}
+
+/// `A = (I - tau * v * v^T) * A`, where `v` is `1` followed by `tail`
+fn reflect<T>(tail: &::strided::Col<T>, tau: T, a: &mut ::strided::Mat<T>) where T: Float {
+    if tau == T::zero() {
+        return
+    }
+
+    let (head, rest) = a.split_at_row_mut(1);
+    let head = &mut head[0];
+
+    // `w = v^T * A`
+    let mut w = head.iter().cloned().collect::<Vec<_>>();
+    for (&v, row) in tail.iter().zip(rest.rows()) {
+        axpy(v, row, &mut w[..]);
+    }
+
+    // `A = A - tau * v * w`
+    axpy(-tau, &w[..], head);
+    for (&v, row) in tail.iter().zip(rest.rows_mut()) {
+        axpy(-tau * v, &w[..], row);
+    }
+}
+
+/// Panics unless `b` has as many rows as the factorized matrix
+fn check_rows<T>(qr: &::strided::Mat<T>, b: &::strided::Mat<T>) {
+    if qr.nrows() != b.nrows() {
+        panic!("{}", Error::InnerSizeMismatch { lhs: (qr.nrows(), qr.nrows()), rhs: b.size() })
+    }
+}
+
+/// Euclidean norm
+fn norm<T, X: ?Sized>(x: &X) -> T where T: Float, X: Vector<T> {
+    dot(x, x).sqrt()
+}
@@ -0,0 +1,158 @@
+//! Singular value decomposition
+
+use blas::dot;
+use error::Error;
+use num::Float;
+
+/// Maximum number of Jacobi sweeps
+const MAX_SWEEPS: usize = 60;
+
+/// Thin singular value decomposition `A = U * diag(s) * V^T`
+pub struct Svd<T> {
+    /// The `m x k` matrix of left singular vectors, where `k = min(m, n)`
+    pub u: Option<Box<::Mat<T>>>,
+    /// The `k` singular values, in descending order
+    pub s: Vec<T>,
+    /// The `k x n` matrix of right singular vectors
+    pub vt: Option<Box<::Mat<T>>>,
+}
+
+/// Computes the singular values and, if `vectors` is true, the singular vectors of the `m x n`
+/// matrix `a`, using the one-sided Jacobi algorithm
+///
+/// The columns of `U` associated to zero singular values are zero. Returns
+/// `Error::NoConvergence` if the columns are not orthogonal after 60 sweeps
+pub fn svd<T>(a: &::strided::Mat<T>, vectors: bool) -> Result<Svd<T>, Error> where T: Float {
+    let (m, n) = a.size();
+
+    if !vectors {
+        let (_, s, _) = try!(jacobi(if m >= n { a } else { a.t() }, false));
+
+        return Ok(Svd { u: None, s: s, vt: None })
+    }
+
+    let (u, s, vt) = if m >= n {
+        let (w, s, vt) = try!(jacobi(a, true));
+
+        (transpose(&w), s, vt.unwrap())
+    } else {
+        // `A^T = V * diag(s) * U^T`
+        let (w, s, vt) = try!(jacobi(a.t(), true));
+
+        (transpose(&vt.unwrap()), s, w)
+    };
+
+    Ok(Svd { u: Some(u), s: s, vt: Some(vt) })
+}
+
+/// One-sided Jacobi SVD of a matrix with at least as many rows as columns
+///
+/// Returns `U^T`, the singular values and `V^T`, sorted by decreasing singular value. If
+/// `vectors` is false, the rotations are not accumulated into `V^T`, which is `None`, and the rows
+/// of `U^T` are not normalized.
+fn jacobi<T>(
+    a: &::strided::Mat<T>,
+    vectors: bool,
+) -> Result<(Box<::Mat<T>>, Vec<T>, Option<Box<::Mat<T>>>), Error> where
+    T: Float,
+{
+    let n = a.ncols();
+    let two = T::one() + T::one();
+
+    // The rotations are applied to the columns of `A`, which are the (contiguous) rows of `w`
+    let mut w = transpose(a);
+    let mut vt = if vectors { Some(::Mat::eye(n)) } else { None };
+
+    let mut sweep = 0;
+    loop {
+        if sweep == MAX_SWEEPS {
+            return Err(Error::NoConvergence { iterations: MAX_SWEEPS })
+        }
+        sweep += 1;
+
+        let mut rotated = false;
+        for p in 0..n {
+            for q in p + 1..n {
+                let alpha = dot(&w[p], &w[p]);
+                let beta = dot(&w[q], &w[q]);
+                let gamma = dot(&w[p], &w[q]);
+
+                if gamma.abs() <= T::epsilon() * (alpha * beta).sqrt() {
+                    continue
+                }
+
+                rotated = true;
+
+                // The rotation that makes the columns `p` and `q` orthogonal
+                let zeta = (beta - alpha) / (two * gamma);
+                let mut t = T::one() / (zeta.abs() + zeta.hypot(T::one()));
+                if zeta < T::zero() {
+                    t = -t;
+                }
+                let c = T::one() / t.hypot(T::one());
+                let s = c * t;
+
+                rotate(&mut w, p, q, c, s);
+                if let Some(ref mut vt) = vt {
+                    rotate(vt, p, q, c, s);
+                }
+            }
+        }
+
+        if !rotated {
+            break
+        }
+    }
+
+    // The norms of the columns are the singular values
+    let mut s = w.rows().map(|row| dot(row, row).sqrt()).collect::<Vec<_>>();
+    if vectors {
+        for (row, &s) in w.rows_mut().zip(&s) {
+            if s != T::zero() {
+                for x in row.iter_mut() {
+                    *x = *x / s;
+                }
+            }
+        }
+    }
+
+    // Selection sort, in descending order
+    for i in 0..n.saturating_sub(1) {
+        let mut k = i;
+        for j in i + 1..n {
+            if s[j] > s[k] {
+                k = j;
+            }
+        }
+
+        if k != i {
+            s.swap(i, k);
+
+            if let Some(ref mut vt) = vt {
+                w.swap_rows(i, k);
+                vt.swap_rows(i, k);
+            }
+        }
+    }
+
+    Ok((w, s, vt))
+}
+
+/// `[x, y] = [c * x - s * y, s * x + c * y]`, where `x` and `y` are the rows `p < q` of `m`
+fn rotate<T>(m: &mut ::strided::Mat<T>, p: usize, q: usize, c: T, s: T) where T: Float {
+    let (top, bottom) = m.split_at_row_mut(q);
+
+    for (x, y) in top[p].iter_mut().zip(bottom[0].iter_mut()) {
+        let (xp, yp) = (*x, *y);
+
+        *x = c * xp - s * yp;
+        *y = s * xp + c * yp;
+    }
+}
+
+/// Returns an owned copy of `A^T`
+fn transpose<T>(a: &::strided::Mat<T>) -> Box<::Mat<T>> where T: Copy {
+    let (m, n) = a.size();
+
+    ::Mat::from_fn((n, m), |i, j| a[(j, i)])
+}
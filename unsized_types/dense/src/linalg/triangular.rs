@@ -0,0 +1,73 @@
+//! Triangular solves
+
+use blas::axpy;
+use error::Error;
+use num::Float;
+
+/// Solves `L * X = B` in place, `b` is overwritten with `X`
+///
+/// Only the lower triangle of `l` is read. If `unit` is true, the diagonal isn't read either and
+/// is assumed to be all ones
+pub fn solve_lower<T>(l: &::strided::Mat<T>, unit: bool, b: &mut ::strided::Mat<T>) where
+    T: Float,
+{
+    let n = check_sizes(l, b);
+
+    for i in 0..n {
+        let (top, bottom) = b.split_at_row_mut(i);
+        let bi = &mut bottom[0];
+
+        for (j, bj) in top.rows().enumerate() {
+            axpy(-l[(i, j)], bj, bi);
+        }
+
+        if !unit {
+            let d = l[(i, i)];
+
+            for x in bi.iter_mut() {
+                *x = *x / d;
+            }
+        }
+    }
+}
+
+/// Solves `U * X = B` in place, `b` is overwritten with `X`
+///
+/// Only the upper triangle of `u` is read. If `unit` is true, the diagonal isn't read either and
+/// is assumed to be all ones
+pub fn solve_upper<T>(u: &::strided::Mat<T>, unit: bool, b: &mut ::strided::Mat<T>) where
+    T: Float,
+{
+    let n = check_sizes(u, b);
+
+    for i in (0..n).rev() {
+        let (top, bottom) = b.split_at_row_mut(i + 1);
+        let bi = &mut top[i];
+
+        for (j, bj) in bottom.rows().enumerate() {
+            axpy(-u[(i, i + 1 + j)], bj, bi);
+        }
+
+        if !unit {
+            let d = u[(i, i)];
+
+            for x in bi.iter_mut() {
+                *x = *x / d;
+            }
+        }
+    }
+}
+
+/// Panics unless `a` is square and has as many columns as `b` has rows, returns the order of `a`
+fn check_sizes<T>(a: &::strided::Mat<T>, b: &::strided::Mat<T>) -> usize {
+    let n = match super::check_square(a) {
+        Ok(n) => n,
+        Err(e) => panic!("{}", e),
+    };
+
+    if n != b.nrows() {
+        panic!("{}", Error::InnerSizeMismatch { lhs: a.size(), rhs: b.size() })
+    }
+
+    n
+}
@@ -1,12 +1,36 @@
+#![feature(augmented_assignments)]
+#![feature(box_raw)]
 #![feature(core)]
+#![feature(duration_span)]
+#![feature(op_assign_traits)]
 #![feature(raw)]
 #![feature(unsized_types)]
+#![feature(zero_one)]
 
+mod bench;
+mod blas;
+mod col_major;
+mod display;
+mod error;
+mod linalg;
+mod map;
 mod mat;
+mod num;
+mod ops;
+mod perm;
+mod range;
+mod reduce;
 mod row;
 mod strided;
+mod structured;
+mod tensor;
 
-use std::mem;
+use std::{env, mem};
+
+use perm::Permutation;
+use range::Step;
+use reduce::{Axis, Norm};
+use tensor::Tensor;
 
 macro_rules! show {
     ($e:expr) => {
@@ -15,7 +39,18 @@ macro_rules! show {
     }
 }
 
+macro_rules! display {
+    ($fmt:tt, $e:expr) => {
+        println!("> println!({:?}, {})", $fmt, stringify!($e));
+        println!(concat!($fmt, "\n"), $e);
+    }
+}
+
 fn main() {
+    if env::args().any(|arg| arg == "--bench") {
+        return bench::gemm(1000)
+    }
+
     let array;
 
     println!("// `array: [i32; 15]`");
@@ -62,11 +97,316 @@ fn main() {
     println!("// In memory-representation of `sm`");
     println!("// `sm.repr(): FatPtr<T, strided::mat::Info>`");
     show!(sm.repr());
+
+    println!("// Any range (`a..b`, `a..`, `..b`, `..`) can be used in any position");
+    show!(&m[(1.., ..3)]);
+
+    println!("// Part of the third row");
+    println!("// `&m[(2, 1..)]: &'array Row<i32>`");
+    show!(&m[(2, 1..)]);
+
+    println!("// Every other row, with the columns in reverse order");
+    println!("// `&m[((..).step(2), (..).step(-1))]: &'array strided::Mat<i32>`");
+    show!(&m[((..).step(2), (..).step(-1))]);
+
+    println!("// First column, from bottom to top");
+    show!(&m[((..).step(-1), 0)]);
+
+    println!("// Transposed view, no element is copied");
+    println!("// `m.t(): &'array ColMajor<i32>`");
+    show!(m.t());
+
+    println!("// Second row of the transposed view, i.e. the second column of `m`");
+    show!(&m.t()[1]);
+
+    let f;
+    println!("// The same data interpreted as a 5-by-3 matrix stored in column-major order");
+    println!("// `f: &'array ColMajor<i32>`");
+    show!(f = ColMajor::reshape(&array, (5, 3)));
+
+    show!(f[(1, 2)]);
+
+    println!("// Matrix product, `m.t()` is not copied");
+    println!("// `m * m.t(): Box<Mat<i32>>`");
+    show!(m * m.t());
+
+    println!("// Dot product of the second and third columns");
+    show!(blas::dot(&m[(.., 1)], &m[(.., 2)]));
+
+    let mut y;
+    println!("// Matrix-vector product: `y = m * x`");
+    show!(y = [0; 3]);
+
+    show!(blas::gemv(1, m, &[1, 0, 0, 0, 1][..], 0, &mut y[..]));
+
+    show!(y);
+
+    let sq;
+    println!("// Structured views of the leading 3-by-3 block, no element is copied");
+    println!("// `sq: &'array strided::Mat<i32>`");
+    show!(sq = &m[(.., ..3)]);
+
+    show!(sq.as_lower());
+
+    show!(sq.as_symmetric());
+
+    show!(sq.as_diag());
+
+    println!("// The strictly upper triangle of a lower view reads as zero");
+    show!(sq.as_lower()[(0, 2)]);
+
+    println!("// Triangular product, only the upper triangle of `sq` is read");
+    show!(sq.as_upper() * sq);
+
+    println!("// Diagonals are strided columns");
+    show!(m.diag());
+
+    show!(m.diag_offset(2));
+
+    show!(m.diag_offset(-1));
+
+    show!(m.anti_diag());
+
+    let t;
+    println!("// The first 12 elements of `array` viewed as a 2-by-3-by-2 tensor");
+    println!("// `t: &'array Tensor<i32>`");
+    show!(t = Tensor::reshape(&array[..12], &[2, 3, 2]));
+
+    show!(t);
+
+    show!(t[[1, 2, 0]]);
+
+    println!("// Axes indexed with a `usize` are dropped");
+    show!(&t[(.., 1, 1..)]);
+
+    println!("// Axis permutation, no element is copied");
+    show!(t.permute(&[2, 0, 1]));
+
+    show!(t.reshaped(&[3, 4]));
+
+    show!(t.permute(&[2, 0, 1]).reshaped(&[4, 3]));
+
+    println!("// 2-D views of a tensor are strided matrices");
+    show!(t[(1,)].as_mat());
+
+    println!("// Broadcasting: subtract the first row from every row");
+    show!(m - &m[0]);
+
+    println!("// `*` is the matrix product, the element-wise product is `mul_elem`");
+    println!("// Scale each row of the first two columns by the first column");
+    show!(m[(.., ..2)].mul_elem(&m[(.., 0)]));
+
+    println!("// A column and a row broadcast to a full matrix");
+    show!(&m[(.., 4)] + &m[0]);
+
+    show!(&m[0] + 1);
+
+    show!(ops::broadcast_size(m.size(), (2, 5)));
+
+    println!("// Reductions");
+    show!(m.sum());
+
+    show!(m.argmax());
+
+    println!("// Per-axis reductions return a row or a column");
+    show!(m.sum_axis(Axis::Rows));
+
+    show!(m.max_axis(Axis::Cols));
+
+    println!("// Any view can be reduced, the positions are relative to the view");
+    show!(sm.argmin_axis(Axis::Cols));
+
+    show!(m.t().argmax());
+
+    println!("// Gather rows into an owned matrix, the indices may repeat");
+    show!(m.select_rows(&[2, 0, 2]));
+
+    let p;
+    println!("// `p: Permutation`, permuting by `p` moves the row `p[i]` to the position `i`");
+    show!(p = Permutation::from_vec(vec![2, 0, 1]).unwrap());
+
+    show!(p.inverse());
+
+    show!(p.compose(&p.inverse()) == Permutation::identity(3));
+
+    println!("// The row interchanges returned by `linalg::lu` can be converted");
+    show!(Permutation::from_swaps(&[2, 2, 2], 3).unwrap() == p);
+
+    show!(Permutation::from_vec(vec![0, 2, 2]));
+
+    let mut q;
+    println!("// Owned copy of `m`");
+    show!(q = m.to_owned());
+
+    println!("// In-place permutations follow the cycles with row swaps, no row is copied");
+    show!(q.permute_rows(&p));
+
+    show!(q);
+
+    show!(q.permute_cols(&Permutation::from_vec(vec![4, 3, 2, 1, 0]).unwrap()));
+
+    show!(q);
+
+    println!("// Scatter the first two rows of `m` into the rows 0 and 2");
+    show!(q.scatter_rows(&[0, 2], &m[(..2, ..)]));
+
+    show!(q);
+
+    println!("// Element-wise map, the element type can change");
+    show!(m.map(|&x| x % 2 == 0));
+
+    println!("// Fused `a * b - c` over three views, without temporaries");
+    show!(sm.zip3_with(&m[(..2, ..3)], &m[(1.., 2..)], |&a, &b, &c| a * b - c));
+
+    println!("// Number of elements greater than 5");
+    show!(m.t().fold(0, |n, &x| if x > 5 { n + 1 } else { n }));
+
+    println!("// Blocks of full rows are contiguous, other views can be copied into a `Box<Mat>`");
+    show!(m[(1.., ..)].as_contiguous().is_some());
+
+    show!(sm.as_contiguous().is_some());
+
+    show!(m.t()[(1..3, ..)].to_owned());
+
+    println!("// `Display` aligns the columns and honors the precision flag");
+    display!("{:.1}", m.map(|&x| x as f64 * 2.5 - 5.));
+
+    println!("// Large matrices are elided");
+    display!("{}", Mat::from_fn((12, 12), |i, j| i * j));
+
+    println!("// Non-panicking accessors");
+    show!(m.get((3, 0)));
+
+    show!(m.get((1.., 2)));
+
+    show!(m.try_get((0..2, 4..6)));
+
+    println!("// Sum of each column");
+    show!(m.cols().map(|c| c.iter().fold(0, |s, &x| s + x)).collect::<Vec<_>>());
+
+    println!("// Elements of `sm` in reverse row-major order");
+    show!(sm.iter().rev().collect::<Vec<_>>());
+
+    let mut o;
+    println!("// Owned matrix where the element at `(i, j)` is `10 * i + j`");
+    println!("// `o: Box<Mat<i32>>`");
+    show!(o = Mat::from_fn((2, 3), |i, j| (10 * i + j) as i32));
+
+    show!(o);
+
+    println!("// `Box<Mat<i32>>` is also a fat pointer");
+    show!(mem::size_of_val(&o));
+
+    println!("// Mutable indexing: element, row and column");
+    show!(o[(1, 2)] = 7);
+
+    show!(o[0][1] += 1);
+
+    show!(o[(.., 0)][1] = -1);
+
+    show!(o);
+
+    println!("// Element-wise arithmetic returns an owned matrix");
+    show!(&o + &o);
+
+    show!(&o[(.., 1..)] * 10);
+
+    println!("// In-place arithmetic works on any mutable view");
+    show!(o[(.., 1..)] += &m[(..2, ..2)]);
+
+    show!(o);
+
+    println!("// Add a ridge term to the diagonal, in place");
+    show!(for x in o.diag_mut().iter_mut() { *x += 100 });
+
+    show!(o);
+
+    println!("// Structured views reject writes to the elements they don't store");
+    show!(o[(.., 1..)].as_upper_mut().try_get_mut((1, 0)));
+
+    println!("// Copy a block into a view, and fill another one");
+    show!(o[(.., 1..)].copy_from(&m[(1.., 3..)]));
+
+    show!(o[(1.., ..)].fill(0));
+
+    show!(o);
+
+    println!("// The owned matrix can be turned back into a `Vec` without copying");
+    show!(o.into_vec());
+
+    let a;
+    println!("// Linear algebra on floating point matrices");
+    println!("// `a: Box<Mat<f64>>`, symmetric positive definite");
+    show!(a = Mat::from_vec(vec![4., -2., 1., -2., 4., -2., 1., -2., 4.], (3, 3)));
+
+    display!("{}", a);
+
+    let mut x;
+    println!("// Solve `a * x = b` where `b = a * [1, 2, 3]^T`, `b` is overwritten with `x`");
+    show!(x = Mat::from_vec(vec![3., 0., 9.], (3, 1)));
+
+    show!(linalg::solve(&a, &mut x));
+
+    display!("{:.3}", x);
+
+    println!("// Determinant, through the LU factorization");
+    display!("{:.3}", linalg::det(&a).unwrap());
+
+    let inv;
+    println!("// `a * inv` is the identity, up to roundoff");
+    show!(inv = linalg::inverse(&a).unwrap());
+
+    show!((&a * &inv - Mat::eye(3)).norm(Norm::Inf).unwrap() < 1e-12);
+
+    let mut l;
+    println!("// Cholesky factor of `a`, computed in place in the lower triangle of a copy");
+    show!(l = a.to_owned());
+
+    show!(linalg::cholesky(&mut l));
+
+    display!("{:.3}", Mat::from_fn((3, 3), |i, j| l.as_lower()[(i, j)]));
+
+    let v;
+    let w;
+    let fit;
+    println!("// Least squares fit of `x0 + x1 * t` to the points `(0, 1), (1, 2), (2, 4)`");
+    println!("// `v` holds the `[1, t]` rows and `w` the ordinates");
+    show!(v = Mat::from_vec(vec![1., 0., 1., 1., 1., 2.], (3, 2)));
+
+    show!(w = Mat::from_vec(vec![1., 2., 4.], (3, 1)));
+
+    show!(fit = linalg::lstsq(&v, &w).unwrap());
+
+    display!("{:.3}", fit.0);
+
+    println!("// Norm of the residual, `sqrt(6) / 6`");
+    display!("{:.3}", fit.1[0]);
+
+    let e;
+    println!("// Eigenvalues, in ascending order, and singular values, in descending order");
+    show!(e = linalg::eigh(&Mat::from_vec(vec![2., 1., 1., 2.], (2, 2)), false).unwrap());
+
+    display!("{:.3}", Mat::reshape(&e.values, (1, 2)));
+
+    let s;
+    show!(s = linalg::svd(&Mat::from_vec(vec![3., 0., 4., 5.], (2, 2)), false).unwrap());
+
+    display!("{:.3}", Mat::reshape(&s.s, (1, 2)));
+
+    println!("// Singular and indefinite matrices are reported");
+    show!(linalg::inverse(&Mat::from_vec(vec![1., 2., 3., 4., 5., 6., 7., 8., 9.], (3, 3))));
+
+    show!(linalg::cholesky(&mut Mat::from_vec(vec![1., 2., 2., 1.], (2, 2))));
+
+    println!("// `ldlt` pivots with 2-by-2 blocks, so it factorizes indefinite matrices");
+    show!(linalg::ldlt(&mut Mat::from_vec(vec![0., 1., 1., 0.], (2, 2))));
 }
 
 /// A dense matrix stored in contiguous memory
 unsized type Mat<T>;
 
+/// A dense matrix stored in contiguous memory, in column-major order
+unsized type ColMajor<T>;
+
 /// A view into the row of a matrix
-#[derive(Debug)]
-pub struct Row<T>([T]);
+pub unsized type Row<T>;
@@ -0,0 +1,186 @@
+//! Element-wise map, zip, fold, copy and fill over strided views
+//!
+//! Views whose elements are contiguous in memory (e.g. a whole `Mat`, or a block of full rows)
+//! are processed with a loop over a flat slice, without any index arithmetic.
+
+use ops;
+
+impl<T> ::strided::Mat<T> {
+    /// Returns the owned matrix `f(self[(i, j)])`, whose elements may have a different type
+    pub fn map<U, F>(&self, f: F) -> Box<::Mat<U>> where F: FnMut(&T) -> U {
+        let elems = match as_slice(self) {
+            Some(slice) => slice.iter().map(f).collect(),
+            None => self.iter().map(f).collect(),
+        };
+
+        ::Mat::from_vec(elems, self.size())
+    }
+
+    /// `self[(i, j)] = f(self[(i, j)])`, where `f` updates the element in place
+    ///
+    /// The elements are visited in memory order
+    pub fn map_inplace<F>(&mut self, mut f: F) where F: FnMut(&mut T) {
+        if let Some(slice) = as_memory_slice_mut(self) {
+            for x in slice {
+                f(x)
+            }
+
+            return
+        }
+
+        let m = if rows_first(self) { self } else { self.t_mut() };
+
+        for x in m.iter_mut() {
+            f(x)
+        }
+    }
+
+    /// Returns the owned matrix `f(self[(i, j)], rhs[(i, j)])`
+    ///
+    /// Panics with `Error::SizeMismatch` if the operands don't have the same size
+    pub fn zip_with<U, V, F>(&self, rhs: &::strided::Mat<U>, mut f: F) -> Box<::Mat<V>> where
+        F: FnMut(&T, &U) -> V,
+    {
+        ops::assert_same_size(self, rhs);
+
+        let elems = match (as_slice(self), as_slice(rhs)) {
+            (Some(lhs), Some(rhs)) => lhs.iter().zip(rhs).map(|(x, y)| f(x, y)).collect(),
+            _ => self.iter().zip(rhs.iter()).map(|(x, y)| f(x, y)).collect(),
+        };
+
+        ::Mat::from_vec(elems, self.size())
+    }
+
+    /// Returns the owned matrix `f(self[(i, j)], b[(i, j)], c[(i, j)])`
+    ///
+    /// Panics with `Error::SizeMismatch` if the operands don't have the same size
+    pub fn zip3_with<U, V, W, F>(
+        &self,
+        b: &::strided::Mat<U>,
+        c: &::strided::Mat<V>,
+        mut f: F,
+    ) -> Box<::Mat<W>> where
+        F: FnMut(&T, &U, &V) -> W,
+    {
+        ops::assert_same_size(self, b);
+        ops::assert_same_size(self, c);
+
+        let elems = match (as_slice(self), as_slice(b), as_slice(c)) {
+            (Some(a), Some(b), Some(c)) => {
+                a.iter().zip(b).zip(c).map(|((x, y), z)| f(x, y, z)).collect()
+            },
+            _ => self.iter().zip(b.iter()).zip(c.iter()).map(|((x, y), z)| f(x, y, z)).collect(),
+        };
+
+        ::Mat::from_vec(elems, self.size())
+    }
+
+    /// `f(&mut self[(i, j)], rhs[(i, j)])` for every `(i, j)`
+    ///
+    /// Panics with `Error::SizeMismatch` if the operands don't have the same size
+    pub fn zip_with_inplace<U, F>(&mut self, rhs: &::strided::Mat<U>, mut f: F) where
+        F: FnMut(&mut T, &U),
+    {
+        ops::assert_same_size(self, rhs);
+
+        if let Some(rhs) = as_slice(rhs) {
+            if let Some(lhs) = as_slice_mut(self) {
+                for (x, y) in lhs.iter_mut().zip(rhs) {
+                    f(x, y)
+                }
+
+                return
+            }
+        }
+
+        for (x, y) in self.iter_mut().zip(rhs.iter()) {
+            f(x, y)
+        }
+    }
+
+    /// Copies the elements of `src` into the view
+    ///
+    /// Panics with `Error::SizeMismatch` if `src` doesn't have the same size
+    pub fn copy_from(&mut self, src: &::strided::Mat<T>) where T: Clone {
+        self.zip_with_inplace(src, |x, y| *x = y.clone())
+    }
+
+    /// Sets every element of the view to `value`
+    pub fn fill(&mut self, value: T) where T: Clone {
+        self.map_inplace(|x| *x = value.clone())
+    }
+
+    /// Copies the view into an owned contiguous matrix
+    pub fn to_owned(&self) -> Box<::Mat<T>> where T: Clone {
+        self.map(|x| x.clone())
+    }
+
+    /// Folds every element into an accumulator: `acc = f(acc, x)`
+    ///
+    /// The elements are visited in memory order, which is not row-major order for transposed or
+    /// column-major views, so the result should not depend on the order
+    pub fn fold<'a, A, F>(&'a self, init: A, f: F) -> A where F: FnMut(A, &'a T) -> A {
+        if let Some(slice) = as_memory_slice(self) {
+            slice.iter().fold(init, f)
+        } else if rows_first(self) {
+            self.iter().fold(init, f)
+        } else {
+            self.t().iter().fold(init, f)
+        }
+    }
+}
+
+impl<T> ::Mat<T> {
+    /// Copies the matrix into an owned matrix
+    ///
+    /// NB Without this method, `to_owned` on a `&Mat` receiver resolves to the blanket
+    /// `ToOwned` impl of `&Mat` (found through autoref before the deref to `strided::Mat`), which
+    /// only copies the reference
+    pub fn to_owned(&self) -> Box<::Mat<T>> where T: Clone {
+        let m: &::strided::Mat<T> = self;
+
+        m.to_owned()
+    }
+}
+
+impl<T> ::ColMajor<T> {
+    /// Copies the matrix into an owned row-major matrix
+    ///
+    /// See `Mat::to_owned`
+    pub fn to_owned(&self) -> Box<::Mat<T>> where T: Clone {
+        let m: &::strided::Mat<T> = self;
+
+        m.to_owned()
+    }
+}
+
+/// Whether walking `m` row by row visits memory in order
+pub fn rows_first<T>(m: &::strided::Mat<T>) -> bool {
+    let info = m.repr().info;
+
+    info.col_stride.abs() <= info.row_stride.abs()
+}
+
+/// The elements of `m` in row-major order, if they are contiguous in memory
+fn as_slice<T>(m: &::strided::Mat<T>) -> Option<&[T]> {
+    m.as_contiguous().map(|m| m.as_slice())
+}
+
+/// Mutable version of `as_slice`
+fn as_slice_mut<T>(m: &mut ::strided::Mat<T>) -> Option<&mut [T]> {
+    m.as_contiguous_mut().map(|m| m.as_mut_slice())
+}
+
+/// The elements of `m` in memory order, if they are contiguous in memory
+fn as_memory_slice<T>(m: &::strided::Mat<T>) -> Option<&[T]> {
+    as_slice(m).or_else(|| as_slice(m.t()))
+}
+
+/// Mutable version of `as_memory_slice`
+fn as_memory_slice_mut<T>(m: &mut ::strided::Mat<T>) -> Option<&mut [T]> {
+    if m.as_contiguous().is_some() {
+        as_slice_mut(m)
+    } else {
+        as_slice_mut(m.t_mut())
+    }
+}
@@ -1,7 +1,8 @@
 use std::marker::Unsized;
-use std::ops::Deref;
+use std::num::{One, Zero};
+use std::ops::{Deref, DerefMut};
 use std::raw::FatPtr;
-use std::{fat_ptr, fmt, mem};
+use std::{fat_ptr, fmt, mem, ptr, slice};
 
 #[derive(Clone, Copy, Debug)]
 struct Info {
@@ -24,18 +25,169 @@ impl<T> ::Mat<T> {
         }
     }
 
+    pub fn reshape_mut<'a>(
+        slice: &'a mut [T],
+        (nrows, ncols): (usize, usize),
+    ) -> &'a mut ::Mat<T> {
+        assert_eq!(slice.len(), nrows * ncols);
+
+        unsafe {
+            &mut *fat_ptr::new(FatPtr {
+                data: slice.as_mut_ptr(),
+                info: Info {
+                    ncols: ncols,
+                    nrows: nrows,
+                }
+            })
+        }
+    }
+
+    /// Creates an owned matrix from a vector of elements stored in row-major order
+    pub fn from_vec(elems: Vec<T>, (nrows, ncols): (usize, usize)) -> Box<::Mat<T>> {
+        assert_eq!(elems.len(), nrows * ncols);
+
+        let mut elems = elems.into_boxed_slice();
+        let data = elems.as_mut_ptr();
+        mem::forget(elems);
+
+        unsafe {
+            Box::from_raw(fat_ptr::new(FatPtr {
+                data: data,
+                info: Info {
+                    ncols: ncols,
+                    nrows: nrows,
+                }
+            }))
+        }
+    }
+
+    /// Creates an owned matrix where the element at `(i, j)` is `f(i, j)`
+    pub fn from_fn<F>((nrows, ncols): (usize, usize), mut f: F) -> Box<::Mat<T>> where
+        F: FnMut(usize, usize) -> T,
+    {
+        let mut elems = Vec::with_capacity(nrows * ncols);
+
+        for i in 0..nrows {
+            for j in 0..ncols {
+                elems.push(f(i, j));
+            }
+        }
+
+        ::Mat::from_vec(elems, (nrows, ncols))
+    }
+
+    /// Creates an owned matrix by copying each row, all the rows must have the same length
+    pub fn from_rows<R>(rows: &[R]) -> Box<::Mat<T>> where
+        R: AsRef<[T]>,
+        T: Clone,
+    {
+        let nrows = rows.len();
+        let ncols = rows.first().map(|row| row.as_ref().len()).unwrap_or(0);
+        let mut elems = Vec::with_capacity(nrows * ncols);
+
+        for row in rows {
+            let row = row.as_ref();
+
+            assert_eq!(row.len(), ncols);
+
+            elems.extend(row.iter().cloned());
+        }
+
+        ::Mat::from_vec(elems, (nrows, ncols))
+    }
+
+    /// Creates an owned matrix filled with zeros
+    pub fn zeros((nrows, ncols): (usize, usize)) -> Box<::Mat<T>> where T: Clone + Zero {
+        ::Mat::from_vec(vec![T::zero(); nrows * ncols], (nrows, ncols))
+    }
+
+    /// Creates an owned `n x n` identity matrix
+    pub fn eye(n: usize) -> Box<::Mat<T>> where T: Clone + One + Zero {
+        let mut m = ::Mat::zeros((n, n));
+
+        for i in 0..n {
+            m[(i, i)] = T::one();
+        }
+
+        m
+    }
+
+    /// Converts the owned matrix into a vector of elements in row-major order, without copying
+    pub fn into_vec(self: Box<Self>) -> Vec<T> {
+        let FatPtr { data, info } = self.repr();
+        let len = info.nrows * info.ncols;
+
+        mem::forget(self);
+
+        unsafe {
+            Vec::from_raw_parts(data, len, len)
+        }
+    }
+
+    /// Returns the elements in row-major order
+    pub fn as_slice(&self) -> &[T] {
+        let FatPtr { data, info } = self.repr();
+
+        unsafe {
+            slice::from_raw_parts(data, info.nrows * info.ncols)
+        }
+    }
+
+    /// Mutable version of `as_slice`
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        let FatPtr { data, info } = self.repr();
+
+        unsafe {
+            slice::from_raw_parts_mut(data, info.nrows * info.ncols)
+        }
+    }
+
+    /// Returns the transpose, which is the same data interpreted as a column-major matrix
+    ///
+    /// NB This shadows `strided::Mat::t`, but `ColMajor` derefs to the same strided view
+    pub fn t(&self) -> &::ColMajor<T> {
+        let info = self.repr().info;
+
+        ::ColMajor::reshape(self.as_slice(), (info.ncols, info.nrows))
+    }
+
+    /// Mutable version of `t`
+    pub fn t_mut(&mut self) -> &mut ::ColMajor<T> {
+        let info = self.repr().info;
+
+        ::ColMajor::reshape_mut(self.as_mut_slice(), (info.ncols, info.nrows))
+    }
+
+    /// Owned version of `t`, no element is copied
+    pub fn into_t(self: Box<Self>) -> Box<::ColMajor<T>> {
+        let info = self.repr().info;
+
+        ::ColMajor::from_vec(self.into_vec(), (info.ncols, info.nrows))
+    }
+
     pub fn repr(&self) -> FatPtr<T, Info> {
         fat_ptr::repr(self)
     }
 }
 
+impl<T> Clone for Box<::Mat<T>> where T: Clone {
+    fn clone(&self) -> Box<::Mat<T>> {
+        let info = self.repr().info;
+
+        ::Mat::from_vec(self.as_slice().to_vec(), (info.nrows, info.ncols))
+    }
+}
+
 impl<T> fmt::Debug for ::Mat<T> where T: fmt::Debug {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         self.deref().fmt(f)
     }
 }
 
-/// A contiguous matrix is also a strided matrix with `stride = ncols`
+/// A contiguous matrix is also a strided matrix with `row_stride = ncols` and `col_stride = 1`
+///
+/// This also makes the `strided::Mat` methods, like `split_at_row` or `split_quad_mut`, available
+/// on `Mat`
 impl<T> Deref for ::Mat<T> {
     type Target = ::strided::Mat<T>;
 
@@ -48,13 +200,43 @@ impl<T> Deref for ::Mat<T> {
                 info: ::strided::mat::Info {
                     nrows: info.nrows,
                     ncols: info.ncols,
-                    stride: info.ncols,
+                    row_stride: info.ncols as isize,
+                    col_stride: 1,
+                }
+            })
+        }
+    }
+}
+
+impl<T> DerefMut for ::Mat<T> {
+    fn deref_mut(&mut self) -> &mut ::strided::Mat<T> {
+        let FatPtr { data, info } = self.repr();
+
+        unsafe {
+            &mut *fat_ptr::new(FatPtr {
+                data: data,
+                info: ::strided::mat::Info {
+                    nrows: info.nrows,
+                    ncols: info.ncols,
+                    row_stride: info.ncols as isize,
+                    col_stride: 1,
                 }
             })
         }
     }
 }
 
+/// Drops the elements, the memory that holds them is freed by `Box`
+impl<T> Drop for ::Mat<T> {
+    fn drop(&mut self) {
+        unsafe {
+            for x in self.as_slice() {
+                ptr::read(x);
+            }
+        }
+    }
+}
+
 impl<T> Unsized for ::Mat<T> {
     type Data = T;
     type Info = Info;
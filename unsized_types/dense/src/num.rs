@@ -0,0 +1,87 @@
+//! Numeric traits used by the linear algebra routines
+
+use std::num::{One, Zero};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// The element type of the BLAS-like routines
+pub trait Scalar: Copy + One + PartialEq + Zero + Add<Output=Self> + Mul<Output=Self> {}
+
+impl<T> Scalar for T where T: Copy + One + PartialEq + Zero + Add<Output=T> + Mul<Output=T> {}
+
+/// Types with a zero that lives forever
+///
+/// The structured views return a reference to it when indexing an element that is not stored
+pub trait StaticZero: 'static {
+    fn zero_ref() -> &'static Self;
+}
+
+macro_rules! static_zero {
+    ($($ty:ident),+) => {
+        $(
+            impl StaticZero for $ty {
+                fn zero_ref() -> &'static $ty {
+                    static ZERO: $ty = 0 as $ty;
+
+                    &ZERO
+                }
+            }
+        )+
+    }
+}
+
+static_zero!(f32, f64, i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+/// The element type of the factorizations
+pub trait Float:
+    Scalar + PartialOrd + Div<Output=Self> + Neg<Output=Self> + Sub<Output=Self>
+{
+    fn abs(self) -> Self;
+
+    /// The difference between `1` and the next representable number
+    fn epsilon() -> Self;
+
+    /// Converts a count, e.g. to compute a mean
+    fn from_usize(n: usize) -> Self;
+
+    /// `sqrt(self^2 + other^2)` without undue overflow or underflow
+    fn hypot(self, other: Self) -> Self;
+
+    /// Neither infinite nor NaN
+    fn is_finite(self) -> bool;
+
+    fn sqrt(self) -> Self;
+}
+
+macro_rules! float {
+    ($($ty:ident),+) => {
+        $(
+            impl Float for $ty {
+                fn abs(self) -> $ty {
+                    $ty::abs(self)
+                }
+
+                fn epsilon() -> $ty {
+                    ::std::$ty::EPSILON
+                }
+
+                fn from_usize(n: usize) -> $ty {
+                    n as $ty
+                }
+
+                fn hypot(self, other: $ty) -> $ty {
+                    $ty::hypot(self, other)
+                }
+
+                fn is_finite(self) -> bool {
+                    $ty::is_finite(self)
+                }
+
+                fn sqrt(self) -> $ty {
+                    $ty::sqrt(self)
+                }
+            }
+        )+
+    }
+}
+
+float!(f32, f64);
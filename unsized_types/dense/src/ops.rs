@@ -0,0 +1,453 @@
+//! Element-wise arithmetic with NumPy-style broadcasting
+//!
+//! The out-of-place operators accept any combination of `&Mat`, `&Box<Mat>`, `&strided::Mat`,
+//! `Box<Mat>`, `&Row` and `&strided::Col` as operands (`&ColMajor` is also accepted as the right
+//! operand), and return an owned `Box<Mat>`. If the left operand is a `Box<Mat>` and the result
+//! has its size, its buffer is reused to store the result.
+//!
+//! A row is a `1 x n` operand and a column is a `n x 1` operand. The operands are broadcast to a
+//! common size: each axis must have the same length in both operands, or a length of one in one
+//! of them, in which case it's repeated. For example, `&m - &means` subtracts the `means` row from
+//! every row of `m`. A scalar can be used as the right operand of any operator.
+//!
+//! `*` between two matrices is the matrix product (see the `blas` module), so there is no `*` or
+//! `/` between a matrix and a row or a column: a `1 x n` matrix and a `Row` with the same elements
+//! would mean different things. The broadcasting element-wise product and quotient are the
+//! `mul_elem` and `div_elem` methods. `*` and `/` by a scalar are element-wise.
+//!
+//! The in-place operators (`+=`, `-=`) work on any mutable view, e.g. `m[(1.., ..2)] += &a`, and so
+//! do `mul_elem_inplace` and `div_elem_inplace`. The right operand is broadcast to the size of the
+//! left operand.
+//!
+//! Operands that can't be broadcast make the operators panic with `Error::BroadcastMismatch`.
+
+use std::fat_ptr;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::raw::FatPtr;
+
+use error::Error;
+
+/// Operands that can be viewed as a strided matrix
+pub trait AsStrided<T> {
+    fn as_strided(&self) -> &::strided::Mat<T>;
+}
+
+impl<'a, T> AsStrided<T> for &'a ::strided::Mat<T> {
+    fn as_strided(&self) -> &::strided::Mat<T> {
+        *self
+    }
+}
+
+impl<'a, T> AsStrided<T> for &'a ::Mat<T> {
+    fn as_strided(&self) -> &::strided::Mat<T> {
+        *self
+    }
+}
+
+impl<'a, T> AsStrided<T> for &'a ::ColMajor<T> {
+    fn as_strided(&self) -> &::strided::Mat<T> {
+        *self
+    }
+}
+
+impl<'a, T> AsStrided<T> for &'a Box<::Mat<T>> {
+    fn as_strided(&self) -> &::strided::Mat<T> {
+        *self
+    }
+}
+
+impl<T> AsStrided<T> for Box<::Mat<T>> {
+    fn as_strided(&self) -> &::strided::Mat<T> {
+        self
+    }
+}
+
+/// A `1 x n` matrix
+impl<'a, T> AsStrided<T> for &'a ::Row<T> {
+    fn as_strided(&self) -> &::strided::Mat<T> {
+        self.as_mat()
+    }
+}
+
+/// A `n x 1` matrix
+impl<'a, T> AsStrided<T> for &'a ::strided::Col<T> {
+    fn as_strided(&self) -> &::strided::Mat<T> {
+        self.as_mat()
+    }
+}
+
+/// Panics with `Error::SizeMismatch` if `lhs` and `rhs` don't have the same size
+pub fn assert_same_size<T>(lhs: &::strided::Mat<T>, rhs: &::strided::Mat<T>) {
+    if lhs.size() != rhs.size() {
+        panic!("{}", Error::SizeMismatch { lhs: lhs.size(), rhs: rhs.size() })
+    }
+}
+
+/// Returns the size of the result of an element-wise operation between operands of size `lhs`
+/// and `rhs`, or `Error::BroadcastMismatch` if they can't be broadcast together
+pub fn broadcast_size(lhs: (usize, usize), rhs: (usize, usize)) -> Result<(usize, usize), Error> {
+    fn axis(lhs: usize, rhs: usize) -> Option<usize> {
+        if lhs == rhs || rhs == 1 {
+            Some(lhs)
+        } else if lhs == 1 {
+            Some(rhs)
+        } else {
+            None
+        }
+    }
+
+    match (axis(lhs.0, rhs.0), axis(lhs.1, rhs.1)) {
+        (Some(nrows), Some(ncols)) => Ok((nrows, ncols)),
+        _ => Err(Error::BroadcastMismatch { lhs: lhs, rhs: rhs }),
+    }
+}
+
+/// Views `m` as a matrix of `size`, the axes of length one are repeated with a zero stride
+///
+/// `size` must be the result of `broadcast_size`
+fn broadcast_to<T>(m: &::strided::Mat<T>, (nrows, ncols): (usize, usize)) -> &::strided::Mat<T> {
+    let FatPtr { data, info } = m.repr();
+
+    unsafe {
+        &*fat_ptr::new(FatPtr {
+            data: data,
+            info: ::strided::mat::Info {
+                nrows: nrows,
+                ncols: ncols,
+                row_stride: if info.nrows == nrows { info.row_stride } else { 0 },
+                col_stride: if info.ncols == ncols { info.col_stride } else { 0 },
+            }
+        })
+    }
+}
+
+/// Broadcasting version of `zip_with`
+fn broadcast_with<T, F>(lhs: &::strided::Mat<T>, rhs: &::strided::Mat<T>, f: F) -> Box<::Mat<T>>
+    where F: FnMut(&T, &T) -> T,
+{
+    let size = match broadcast_size(lhs.size(), rhs.size()) {
+        Ok(size) => size,
+        Err(e) => panic!("{}", e),
+    };
+
+    broadcast_to(lhs, size).zip_with(broadcast_to(rhs, size), f)
+}
+
+/// Broadcasting version of `zip_with_inplace`, `rhs` is broadcast to the size of `lhs`
+fn broadcast_with_inplace<T, F>(
+    lhs: &mut ::strided::Mat<T>,
+    rhs: &::strided::Mat<T>,
+    mut f: F,
+) where
+    F: FnMut(&T, &T) -> T,
+{
+    if broadcast_size(lhs.size(), rhs.size()) != Ok(lhs.size()) {
+        panic!("{}", Error::BroadcastMismatch { lhs: lhs.size(), rhs: rhs.size() })
+    }
+
+    let size = lhs.size();
+
+    lhs.zip_with_inplace(broadcast_to(rhs, size), |x, y| *x = f(x, y))
+}
+
+/// The element-wise product and quotient, `rhs` can be any operand accepted by the operators,
+/// e.g. `m.mul_elem(&row)` scales every row of `m` by `row`
+impl<T> ::strided::Mat<T> {
+    /// Element-wise multiplication, with broadcasting
+    pub fn mul_elem<R>(&self, rhs: R) -> Box<::Mat<T>> where
+        R: AsStrided<T>,
+        T: Clone + Mul<Output=T>,
+    {
+        broadcast_with(self, rhs.as_strided(), |x, y| x.clone() * y.clone())
+    }
+
+    /// Element-wise division, with broadcasting
+    pub fn div_elem<R>(&self, rhs: R) -> Box<::Mat<T>> where
+        R: AsStrided<T>,
+        T: Clone + Div<Output=T>,
+    {
+        broadcast_with(self, rhs.as_strided(), |x, y| x.clone() / y.clone())
+    }
+
+    /// In-place version of `mul_elem`, `rhs` is broadcast to the size of `self`
+    pub fn mul_elem_inplace<R>(&mut self, rhs: R) where
+        R: AsStrided<T>,
+        T: Clone + Mul<Output=T>,
+    {
+        broadcast_with_inplace(self, rhs.as_strided(), |x, y| x.clone() * y.clone())
+    }
+
+    /// In-place version of `div_elem`, `rhs` is broadcast to the size of `self`
+    pub fn div_elem_inplace<R>(&mut self, rhs: R) where
+        R: AsStrided<T>,
+        T: Clone + Div<Output=T>,
+    {
+        broadcast_with_inplace(self, rhs.as_strided(), |x, y| x.clone() / y.clone())
+    }
+}
+
+/// Element-wise operators between two operands
+macro_rules! binop {
+    (@impl $Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident, $rhs:ty) => {
+        impl<'b, T> $OpAssign<$rhs> for ::strided::Mat<T> where T: Clone + $Op<Output=T> {
+            fn $op_assign(&mut self, rhs: $rhs) {
+                broadcast_with_inplace(self, rhs.as_strided(), |x, y| x.clone().$op(y.clone()))
+            }
+        }
+
+        impl<'b, T> $OpAssign<$rhs> for ::Mat<T> where T: Clone + $Op<Output=T> {
+            fn $op_assign(&mut self, rhs: $rhs) {
+                let lhs: &mut ::strided::Mat<T> = self;
+
+                lhs.$op_assign(rhs)
+            }
+        }
+
+        impl<'b, T> $OpAssign<$rhs> for Box<::Mat<T>> where T: Clone + $Op<Output=T> {
+            fn $op_assign(&mut self, rhs: $rhs) {
+                let lhs: &mut ::strided::Mat<T> = self;
+
+                lhs.$op_assign(rhs)
+            }
+        }
+
+        impl<'b, T> $OpAssign<$rhs> for ::Row<T> where T: Clone + $Op<Output=T> {
+            fn $op_assign(&mut self, rhs: $rhs) {
+                self.as_mat_mut().$op_assign(rhs)
+            }
+        }
+
+        impl<'b, T> $OpAssign<$rhs> for ::strided::Col<T> where T: Clone + $Op<Output=T> {
+            fn $op_assign(&mut self, rhs: $rhs) {
+                self.as_mat_mut().$op_assign(rhs)
+            }
+        }
+
+        impl<'a, 'b, T> $Op<$rhs> for &'a ::strided::Mat<T> where T: Clone + $Op<Output=T> {
+            type Output = Box<::Mat<T>>;
+
+            fn $op(self, rhs: $rhs) -> Box<::Mat<T>> {
+                broadcast_with(self, rhs.as_strided(), |x, y| x.clone().$op(y.clone()))
+            }
+        }
+
+        impl<'a, 'b, T> $Op<$rhs> for &'a ::Mat<T> where T: Clone + $Op<Output=T> {
+            type Output = Box<::Mat<T>>;
+
+            fn $op(self, rhs: $rhs) -> Box<::Mat<T>> {
+                let lhs: &::strided::Mat<T> = self;
+
+                lhs.$op(rhs)
+            }
+        }
+
+        impl<'a, 'b, T> $Op<$rhs> for &'a Box<::Mat<T>> where T: Clone + $Op<Output=T> {
+            type Output = Box<::Mat<T>>;
+
+            fn $op(self, rhs: $rhs) -> Box<::Mat<T>> {
+                let lhs: &::strided::Mat<T> = self;
+
+                lhs.$op(rhs)
+            }
+        }
+
+        /// Reuses the buffer of the left operand, unless the result is larger
+        impl<'b, T> $Op<$rhs> for Box<::Mat<T>> where T: Clone + $Op<Output=T> {
+            type Output = Box<::Mat<T>>;
+
+            fn $op(mut self, rhs: $rhs) -> Box<::Mat<T>> {
+                if broadcast_size(self.size(), rhs.as_strided().size()) == Ok(self.size()) {
+                    self.$op_assign(rhs);
+                    self
+                } else {
+                    let lhs: &::strided::Mat<T> = &self;
+
+                    lhs.$op(rhs)
+                }
+            }
+        }
+
+        impl<'a, 'b, T> $Op<$rhs> for &'a ::Row<T> where T: Clone + $Op<Output=T> {
+            type Output = Box<::Mat<T>>;
+
+            fn $op(self, rhs: $rhs) -> Box<::Mat<T>> {
+                self.as_mat().$op(rhs)
+            }
+        }
+
+        impl<'a, 'b, T> $Op<$rhs> for &'a ::strided::Col<T> where T: Clone + $Op<Output=T> {
+            type Output = Box<::Mat<T>>;
+
+            fn $op(self, rhs: $rhs) -> Box<::Mat<T>> {
+                self.as_mat().$op(rhs)
+            }
+        }
+    };
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident, [$($rhs:ty),+]) => {
+        $(binop!(@impl $Op, $op, $OpAssign, $op_assign, $rhs);)+
+    };
+}
+
+binop!(Add, add, AddAssign, add_assign, [
+    &'b ::strided::Mat<T>,
+    &'b ::Mat<T>,
+    &'b ::ColMajor<T>,
+    &'b Box<::Mat<T>>,
+    Box<::Mat<T>>,
+    &'b ::Row<T>,
+    &'b ::strided::Col<T>
+]);
+binop!(Sub, sub, SubAssign, sub_assign, [
+    &'b ::strided::Mat<T>,
+    &'b ::Mat<T>,
+    &'b ::ColMajor<T>,
+    &'b Box<::Mat<T>>,
+    Box<::Mat<T>>,
+    &'b ::Row<T>,
+    &'b ::strided::Col<T>
+]);
+
+/// Operators between an operand and a scalar
+macro_rules! scalarop {
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident) => {
+        impl<T> $OpAssign<T> for ::strided::Mat<T> where T: Clone + $Op<Output=T> {
+            fn $op_assign(&mut self, rhs: T) {
+                for x in self.iter_mut() {
+                    *x = x.clone().$op(rhs.clone());
+                }
+            }
+        }
+
+        impl<T> $OpAssign<T> for ::Mat<T> where T: Clone + $Op<Output=T> {
+            fn $op_assign(&mut self, rhs: T) {
+                let lhs: &mut ::strided::Mat<T> = self;
+
+                lhs.$op_assign(rhs)
+            }
+        }
+
+        impl<T> $OpAssign<T> for Box<::Mat<T>> where T: Clone + $Op<Output=T> {
+            fn $op_assign(&mut self, rhs: T) {
+                let lhs: &mut ::strided::Mat<T> = self;
+
+                lhs.$op_assign(rhs)
+            }
+        }
+
+        impl<T> $OpAssign<T> for ::Row<T> where T: Clone + $Op<Output=T> {
+            fn $op_assign(&mut self, rhs: T) {
+                self.as_mat_mut().$op_assign(rhs)
+            }
+        }
+
+        impl<T> $OpAssign<T> for ::strided::Col<T> where T: Clone + $Op<Output=T> {
+            fn $op_assign(&mut self, rhs: T) {
+                self.as_mat_mut().$op_assign(rhs)
+            }
+        }
+
+        impl<'a, T> $Op<T> for &'a ::strided::Mat<T> where T: Clone + $Op<Output=T> {
+            type Output = Box<::Mat<T>>;
+
+            fn $op(self, rhs: T) -> Box<::Mat<T>> {
+                let elems = self.iter().map(|x| x.clone().$op(rhs.clone())).collect();
+
+                ::Mat::from_vec(elems, self.size())
+            }
+        }
+
+        impl<'a, T> $Op<T> for &'a ::Mat<T> where T: Clone + $Op<Output=T> {
+            type Output = Box<::Mat<T>>;
+
+            fn $op(self, rhs: T) -> Box<::Mat<T>> {
+                let lhs: &::strided::Mat<T> = self;
+
+                lhs.$op(rhs)
+            }
+        }
+
+        impl<'a, T> $Op<T> for &'a Box<::Mat<T>> where T: Clone + $Op<Output=T> {
+            type Output = Box<::Mat<T>>;
+
+            fn $op(self, rhs: T) -> Box<::Mat<T>> {
+                let lhs: &::strided::Mat<T> = self;
+
+                lhs.$op(rhs)
+            }
+        }
+
+        /// Reuses the buffer of the left operand
+        impl<T> $Op<T> for Box<::Mat<T>> where T: Clone + $Op<Output=T> {
+            type Output = Box<::Mat<T>>;
+
+            fn $op(mut self, rhs: T) -> Box<::Mat<T>> {
+                self.$op_assign(rhs);
+                self
+            }
+        }
+
+        impl<'a, T> $Op<T> for &'a ::Row<T> where T: Clone + $Op<Output=T> {
+            type Output = Box<::Mat<T>>;
+
+            fn $op(self, rhs: T) -> Box<::Mat<T>> {
+                self.as_mat().$op(rhs)
+            }
+        }
+
+        impl<'a, T> $Op<T> for &'a ::strided::Col<T> where T: Clone + $Op<Output=T> {
+            type Output = Box<::Mat<T>>;
+
+            fn $op(self, rhs: T) -> Box<::Mat<T>> {
+                self.as_mat().$op(rhs)
+            }
+        }
+    }
+}
+
+scalarop!(Add, add, AddAssign, add_assign);
+scalarop!(Sub, sub, SubAssign, sub_assign);
+scalarop!(Mul, mul, MulAssign, mul_assign);
+scalarop!(Div, div, DivAssign, div_assign);
+
+impl<'a, T> Neg for &'a ::strided::Mat<T> where T: Clone + Neg<Output=T> {
+    type Output = Box<::Mat<T>>;
+
+    fn neg(self) -> Box<::Mat<T>> {
+        let elems = self.iter().map(|x| -x.clone()).collect();
+
+        ::Mat::from_vec(elems, self.size())
+    }
+}
+
+impl<'a, T> Neg for &'a ::Mat<T> where T: Clone + Neg<Output=T> {
+    type Output = Box<::Mat<T>>;
+
+    fn neg(self) -> Box<::Mat<T>> {
+        let m: &::strided::Mat<T> = self;
+
+        -m
+    }
+}
+
+impl<'a, T> Neg for &'a Box<::Mat<T>> where T: Clone + Neg<Output=T> {
+    type Output = Box<::Mat<T>>;
+
+    fn neg(self) -> Box<::Mat<T>> {
+        let m: &::strided::Mat<T> = self;
+
+        -m
+    }
+}
+
+/// Reuses the buffer of the operand
+impl<T> Neg for Box<::Mat<T>> where T: Clone + Neg<Output=T> {
+    type Output = Box<::Mat<T>>;
+
+    fn neg(mut self) -> Box<::Mat<T>> {
+        for x in self.iter_mut() {
+            *x = -x.clone();
+        }
+
+        self
+    }
+}
@@ -0,0 +1,180 @@
+//! Row and column permutations, gather and scatter
+
+use std::ops::Index;
+use std::{cmp, mem};
+
+use blas::assert_same_len;
+use error::Error;
+
+/// A permutation of `0..n`, used to reorder rows or columns
+///
+/// `p[i]` is the source position of the element that ends up at position `i`: permuting the rows
+/// of `m` by `p` moves the row `p[i]` to the position `i`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Permutation {
+    indices: Vec<usize>,
+}
+
+impl Permutation {
+    /// The permutation that leaves `n` elements in place
+    pub fn identity(n: usize) -> Permutation {
+        Permutation {
+            indices: (0..n).collect(),
+        }
+    }
+
+    /// Returns `Error::NotPermutation` if some index is out of bounds or repeated
+    pub fn from_vec(indices: Vec<usize>) -> Result<Permutation, Error> {
+        let mut seen = vec![false; indices.len()];
+
+        for &i in &indices {
+            match seen.get_mut(i) {
+                Some(seen) if !*seen => *seen = true,
+                _ => return Err(Error::NotPermutation { index: i }),
+            }
+        }
+
+        Ok(Permutation {
+            indices: indices,
+        })
+    }
+
+    /// Converts the LAPACK-style row interchanges of `n` elements, where the row `k` is swapped
+    /// with the row `swaps[k]` in order, like the pivots returned by `linalg::lu`
+    ///
+    /// Returns `Error::SwapOutOfBounds` if a swap is out of bounds, i.e. if there are more than `n`
+    /// swaps or one of them targets an element past `n`
+    pub fn from_swaps(swaps: &[usize], n: usize) -> Result<Permutation, Error> {
+        let mut p = Permutation::identity(n);
+
+        for (k, &i) in swaps.iter().enumerate() {
+            if k >= n || i >= n {
+                return Err(Error::SwapOutOfBounds { position: k, index: i, len: n })
+            }
+
+            p.indices.swap(k, i);
+        }
+
+        Ok(p)
+    }
+
+    /// Number of elements
+    pub fn len(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Returns `[p[0], p[1], ..]`
+    pub fn as_slice(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// Returns the permutation that applies `self` and then `other`
+    ///
+    /// Panics with `Error::LenMismatch` if the permutations don't have the same length
+    pub fn compose(&self, other: &Permutation) -> Permutation {
+        assert_same_len(self.len(), other.len());
+
+        Permutation {
+            indices: other.indices.iter().map(|&i| self.indices[i]).collect(),
+        }
+    }
+
+    /// Returns the permutation that undoes `self`
+    pub fn inverse(&self) -> Permutation {
+        let mut indices = vec![0; self.len()];
+
+        for (i, &j) in self.indices.iter().enumerate() {
+            indices[j] = i;
+        }
+
+        Permutation {
+            indices: indices,
+        }
+    }
+}
+
+impl Index<usize> for Permutation {
+    type Output = usize;
+
+    fn index(&self, i: usize) -> &usize {
+        &self.indices[i]
+    }
+}
+
+impl<T> ::strided::Mat<T> {
+    /// Swaps the rows `i` and `j`
+    pub fn swap_rows(&mut self, i: usize, j: usize) {
+        if i != j {
+            let (i, j) = (cmp::min(i, j), cmp::max(i, j));
+            let (top, bottom) = self.split_at_row_mut(j);
+
+            for (x, y) in top[i].iter_mut().zip(bottom[0].iter_mut()) {
+                mem::swap(x, y);
+            }
+        }
+    }
+
+    /// Permutes the rows in place: the row `perm[i]` moves to the position `i`
+    ///
+    /// Each cycle of the permutation is followed with row swaps, so no row is copied. Panics with
+    /// `Error::LenMismatch` if `perm` doesn't have `nrows` elements
+    pub fn permute_rows(&mut self, perm: &Permutation) {
+        assert_same_len(self.nrows(), perm.len());
+
+        let mut done = vec![false; perm.len()];
+
+        for start in 0..perm.len() {
+            if done[start] {
+                continue
+            }
+
+            done[start] = true;
+
+            let mut i = start;
+            loop {
+                let j = perm[i];
+
+                if j == start {
+                    break
+                }
+
+                self.swap_rows(i, j);
+                done[j] = true;
+                i = j;
+            }
+        }
+    }
+
+    /// Permutes the columns in place: the column `perm[j]` moves to the position `j`
+    ///
+    /// Panics with `Error::LenMismatch` if `perm` doesn't have `ncols` elements
+    pub fn permute_cols(&mut self, perm: &Permutation) {
+        self.t_mut().permute_rows(perm)
+    }
+
+    /// Gathers the rows `indices` into an owned matrix, the indices may repeat
+    ///
+    /// Panics with `Error::RowOutOfBounds` if an index is out of bounds
+    pub fn select_rows(&self, indices: &[usize]) -> Box<::Mat<T>> where T: Clone {
+        let mut elems = Vec::with_capacity(indices.len() * self.ncols());
+
+        for &i in indices {
+            elems.extend(self[i].iter().cloned());
+        }
+
+        ::Mat::from_vec(elems, (indices.len(), self.ncols()))
+    }
+
+    /// Scatters the rows of `src` into the view: the row `k` of `src` is copied into the row
+    /// `indices[k]`
+    ///
+    /// Panics with `Error::LenMismatch` if `src` doesn't have one row per index, and with
+    /// `Error::SizeMismatch` if the rows don't have the same length
+    pub fn scatter_rows(&mut self, indices: &[usize], src: &::strided::Mat<T>) where T: Clone {
+        assert_same_len(indices.len(), src.nrows());
+
+        for (&i, row) in indices.iter().zip(src.rows()) {
+            self[i].as_mat_mut().copy_from(row.as_mat())
+        }
+    }
+}
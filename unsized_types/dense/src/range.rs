@@ -0,0 +1,133 @@
+//! Ranges of indices used to slice the axes of a matrix
+//!
+//! Any type that implements `Slice` can be used in either position of a 2-D index, and it can be
+//! mixed with `usize`:
+//!
+//! - `m[(usize, usize)]` is an element
+//! - `&m[(usize, Slice)]` is a `Row`
+//! - `&m[(Slice, usize)]` is a `strided::Col`
+//! - `&m[(Slice, Slice)]` is a `strided::Mat`
+
+use std::ops::{Range, RangeFrom, RangeFull, RangeTo};
+
+/// A range of indices along one of the axes of a matrix
+pub trait Slice: Sized {
+    /// Resolves the range against an axis of length `len`, returns the offending `start..end`
+    /// range if it doesn't fit in the axis
+    fn try_resolve(self, len: usize) -> Result<Indices, Range<usize>>;
+
+    /// Panicking version of `try_resolve`
+    fn resolve(self, len: usize) -> Indices {
+        match self.try_resolve(len) {
+            Ok(indices) => indices,
+            Err(range) => panic!("range {:?} out of bounds of an axis of length {}", range, len),
+        }
+    }
+}
+
+/// `len` indices, starting at `start` and `step` apart
+#[derive(Clone, Copy, Debug)]
+pub struct Indices {
+    pub len: usize,
+    pub start: usize,
+    pub step: isize,
+}
+
+/// The range `start <= x <= end`
+///
+/// NB This is a stand-in for the `a...b` syntax, which this toolchain doesn't support yet
+#[derive(Clone, Copy, Debug)]
+pub struct RangeInclusive {
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Shorthand for `RangeInclusive { start: start, end: end }`
+pub fn inclusive(start: usize, end: usize) -> RangeInclusive {
+    RangeInclusive {
+        start: start,
+        end: end,
+    }
+}
+
+/// A range traversed with a step, created with the `Step::step` method
+///
+/// A negative step traverses the range backwards: `(0..4).step(-2)` contains the indices `3, 1`
+#[derive(Clone, Copy, Debug)]
+pub struct StepRange<R> {
+    range: R,
+    step: isize,
+}
+
+/// NumPy's `a:b:step`
+pub trait Step: Slice {
+    fn step(self, step: isize) -> StepRange<Self> {
+        assert!(step != 0);
+
+        StepRange {
+            range: self,
+            step: step,
+        }
+    }
+}
+
+impl<R> Step for R where R: Slice {}
+
+impl Slice for Range<usize> {
+    fn try_resolve(self, len: usize) -> Result<Indices, Range<usize>> {
+        if self.start > self.end || self.end > len {
+            return Err(self)
+        }
+
+        Ok(Indices {
+            len: self.end - self.start,
+            start: self.start,
+            step: 1,
+        })
+    }
+}
+
+impl Slice for RangeFrom<usize> {
+    fn try_resolve(self, len: usize) -> Result<Indices, Range<usize>> {
+        (self.start..len).try_resolve(len)
+    }
+}
+
+impl Slice for RangeTo<usize> {
+    fn try_resolve(self, len: usize) -> Result<Indices, Range<usize>> {
+        (0..self.end).try_resolve(len)
+    }
+}
+
+impl Slice for RangeInclusive {
+    fn try_resolve(self, len: usize) -> Result<Indices, Range<usize>> {
+        (self.start..self.end.saturating_add(1)).try_resolve(len)
+    }
+}
+
+impl Slice for RangeFull {
+    fn try_resolve(self, len: usize) -> Result<Indices, Range<usize>> {
+        Ok(Indices {
+            len: len,
+            start: 0,
+            step: 1,
+        })
+    }
+}
+
+impl<R> Slice for StepRange<R> where R: Slice {
+    fn try_resolve(self, len: usize) -> Result<Indices, Range<usize>> {
+        let Indices { len, start, step } = try!(self.range.try_resolve(len));
+        let abs = self.step.abs() as usize;
+
+        Ok(Indices {
+            len: (len + abs - 1) / abs,
+            start: if self.step > 0 || len == 0 {
+                start
+            } else {
+                (start as isize + (len - 1) as isize * step) as usize
+            },
+            step: step * self.step,
+        })
+    }
+}
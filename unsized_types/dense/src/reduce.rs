@@ -0,0 +1,341 @@
+//! Reductions over a whole matrix or along one of its axes
+//!
+//! The elements are visited in memory order: if the columns of a view are closer together than
+//! its rows (e.g. a `ColMajor` matrix or a transposed view), the view is walked column by column.
+//! The per-axis reductions accumulate a whole row at a time in that case, instead of striding down
+//! each column.
+
+use std::mem;
+use std::num::{One, Zero};
+use std::ops::{Add, Mul};
+
+use error::Error;
+use linalg;
+use map::rows_first;
+use num::Float;
+
+/// The axis along which a reduction is performed
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Axis {
+    /// Reduces each column over its rows, the result is a `1 x ncols` row
+    Rows,
+    /// Reduces each row over its columns, the result is a `nrows x 1` column
+    Cols,
+}
+
+/// Matrix norms
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Norm {
+    /// Maximum absolute column sum
+    One,
+    /// Largest singular value, or Euclidean norm along an axis
+    Two,
+    /// Maximum absolute row sum
+    Inf,
+    /// Square root of the sum of the squares of the elements
+    Fro,
+}
+
+impl<T> ::strided::Mat<T> {
+    /// Sum of the elements, zero if the matrix is empty
+    pub fn sum(&self) -> T where T: Clone + Add<Output=T> + Zero {
+        self.fold(T::zero(), |s, x| s + x.clone())
+    }
+
+    /// Per-axis version of `sum`
+    pub fn sum_axis(&self, axis: Axis) -> Box<::Mat<T>> where T: Clone + Add<Output=T> + Zero {
+        let sums = fold_axis(self, axis, T::zero(), |s, _, x| s + x.clone());
+
+        to_mat(sums, axis)
+    }
+
+    /// Product of the elements, one if the matrix is empty
+    pub fn product(&self) -> T where T: Clone + Mul<Output=T> + One {
+        self.fold(T::one(), |p, x| p * x.clone())
+    }
+
+    /// Per-axis version of `product`
+    pub fn product_axis(&self, axis: Axis) -> Box<::Mat<T>> where
+        T: Clone + Mul<Output=T> + One,
+    {
+        let products = fold_axis(self, axis, T::one(), |p, _, x| p * x.clone());
+
+        to_mat(products, axis)
+    }
+
+    /// Smallest element, or `None` if the matrix has no element that can be compared
+    ///
+    /// Elements that can't be compared, like NaNs, are skipped wherever they are
+    pub fn min(&self) -> Option<&T> where T: PartialOrd {
+        self.fold(None, |min, x| match min {
+            _ if !comparable(x) => min,
+            Some(min) if !(x < min) => Some(min),
+            _ => Some(x),
+        })
+    }
+
+    /// Largest element, or `None` if the matrix has no element that can be compared
+    ///
+    /// Elements that can't be compared, like NaNs, are skipped wherever they are
+    pub fn max(&self) -> Option<&T> where T: PartialOrd {
+        self.fold(None, |max, x| match max {
+            _ if !comparable(x) => max,
+            Some(max) if !(x > max) => Some(max),
+            _ => Some(x),
+        })
+    }
+
+    /// Per-axis version of `min`, panics if the reduced axis is empty
+    pub fn min_axis(&self, axis: Axis) -> Box<::Mat<T>> where T: Clone + PartialOrd {
+        let mins = self.argmin_axis(axis).iter().enumerate().map(|(k, &i)| {
+            self.reduced(axis, k, i).clone()
+        }).collect();
+
+        to_mat(mins, axis)
+    }
+
+    /// Per-axis version of `max`, panics if the reduced axis is empty
+    pub fn max_axis(&self, axis: Axis) -> Box<::Mat<T>> where T: Clone + PartialOrd {
+        let maxs = self.argmax_axis(axis).iter().enumerate().map(|(k, &i)| {
+            self.reduced(axis, k, i).clone()
+        }).collect();
+
+        to_mat(maxs, axis)
+    }
+
+    /// Position of the smallest element, or `None` if the matrix has no element that can be
+    /// compared
+    ///
+    /// Like `min`, NaNs are skipped. If there are several minima, returns the first one in
+    /// row-major order
+    pub fn argmin(&self) -> Option<(usize, usize)> where T: PartialOrd {
+        arg(self, |x, best| x < best)
+    }
+
+    /// Position of the largest element, or `None` if the matrix has no element that can be
+    /// compared
+    ///
+    /// Like `max`, NaNs are skipped. If there are several maxima, returns the first one in
+    /// row-major order
+    pub fn argmax(&self) -> Option<(usize, usize)> where T: PartialOrd {
+        arg(self, |x, best| x > best)
+    }
+
+    /// Per-axis version of `argmin`: the row (`Axis::Rows`) or column (`Axis::Cols`) index of the
+    /// minimum of each column or row, `0` if none of its elements can be compared. Panics if the
+    /// reduced axis is empty
+    pub fn argmin_axis(&self, axis: Axis) -> Vec<usize> where T: PartialOrd {
+        arg_axis(self, axis, |x, best| x < best)
+    }
+
+    /// Per-axis version of `argmax`, panics if the reduced axis is empty
+    pub fn argmax_axis(&self, axis: Axis) -> Vec<usize> where T: PartialOrd {
+        arg_axis(self, axis, |x, best| x > best)
+    }
+
+    /// Arithmetic mean of the elements, NaN if the matrix is empty
+    pub fn mean(&self) -> T where T: Float {
+        self.sum() / T::from_usize(self.nrows() * self.ncols())
+    }
+
+    /// Per-axis version of `mean`
+    pub fn mean_axis(&self, axis: Axis) -> Box<::Mat<T>> where T: Float {
+        let n = T::from_usize(self.reduced_len(axis));
+
+        self.sum_axis(axis) / n
+    }
+
+    /// Population variance of the elements, NaN if the matrix is empty
+    pub fn var(&self) -> T where T: Float {
+        self.fold(Welford::new(), |w, &x| w.push(x)).var()
+    }
+
+    /// Per-axis version of `var`
+    pub fn var_axis(&self, axis: Axis) -> Box<::Mat<T>> where T: Float {
+        let vars = fold_axis(self, axis, Welford::new(), |w, _, &x| w.push(x));
+
+        to_mat(vars.into_iter().map(|w| w.var()).collect(), axis)
+    }
+
+    /// Returns the `norm` of the matrix
+    ///
+    /// `Norm::Two` is computed from the singular values, which may fail to converge
+    pub fn norm(&self, norm: Norm) -> Result<T, Error> where T: Float {
+        Ok(match norm {
+            Norm::One => largest(self.norm_axis(Norm::One, Axis::Rows)),
+            Norm::Two => {
+                let svd = try!(linalg::svd(self, false));
+
+                svd.s.first().cloned().unwrap_or(T::zero())
+            },
+            Norm::Inf => largest(self.norm_axis(Norm::One, Axis::Cols)),
+            Norm::Fro => self.fold(T::zero(), |s, &x| s + x * x).sqrt(),
+        })
+    }
+
+    /// Vector `norm` of each column (`Axis::Rows`) or row (`Axis::Cols`)
+    ///
+    /// `Norm::One` is the sum of the absolute values, `Norm::Two` and `Norm::Fro` the Euclidean
+    /// norm and `Norm::Inf` the largest absolute value
+    pub fn norm_axis(&self, norm: Norm, axis: Axis) -> Box<::Mat<T>> where T: Float {
+        let norms = match norm {
+            Norm::One => fold_axis(self, axis, T::zero(), |s, _, &x| s + x.abs()),
+            Norm::Two | Norm::Fro => {
+                let sums = fold_axis(self, axis, T::zero(), |s, _, &x| s + x * x);
+
+                sums.into_iter().map(|s| s.sqrt()).collect()
+            },
+            Norm::Inf => {
+                fold_axis(self, axis, T::zero(), |m, _, &x| if x.abs() > m { x.abs() } else { m })
+            },
+        };
+
+        to_mat(norms, axis)
+    }
+
+    /// Length of the axis that `axis` reduces
+    fn reduced_len(&self, axis: Axis) -> usize {
+        match axis {
+            Axis::Rows => self.nrows(),
+            Axis::Cols => self.ncols(),
+        }
+    }
+
+    /// The element at position `i` of the `k`-th column (`Axis::Rows`) or row (`Axis::Cols`)
+    fn reduced(&self, axis: Axis, k: usize, i: usize) -> &T {
+        match axis {
+            Axis::Rows => &self[(i, k)],
+            Axis::Cols => &self[(k, i)],
+        }
+    }
+}
+
+/// Largest of the (non-negative) vector norms
+fn largest<T>(norms: Box<::Mat<T>>) -> T where T: Float {
+    norms.iter().fold(T::zero(), |m, &x| if x > m { x } else { m })
+}
+
+/// Whether `x` can be compared at all, i.e. it's not a NaN
+fn comparable<T>(x: &T) -> bool where T: PartialOrd {
+    x.partial_cmp(x).is_some()
+}
+
+/// Folds each column (`Axis::Rows`) or row (`Axis::Cols`) of `m`, in memory order
+///
+/// `f(acc, i, x)` receives the position `i` of `x` along the reduced axis. `init` is cloned once
+/// per accumulator, the accumulators themselves are moved into `f`
+fn fold_axis<'a, T, A, F>(m: &'a ::strided::Mat<T>, axis: Axis, init: A, mut f: F) -> Vec<A> where
+    A: Clone,
+    F: FnMut(A, usize, &'a T) -> A,
+{
+    // Reducing the rows of `m` is reducing the columns of `m^T`
+    let m = match axis {
+        Axis::Rows => m,
+        Axis::Cols => m.t(),
+    };
+
+    if rows_first(m) {
+        // `None` only while an accumulator is moved out to `f`
+        let mut acc = vec![Some(init); m.ncols()];
+
+        for (i, row) in m.rows().enumerate() {
+            for (a, x) in acc.iter_mut().zip(row.iter()) {
+                let prev = mem::replace(a, None).unwrap();
+
+                *a = Some(f(prev, i, x));
+            }
+        }
+
+        acc.into_iter().map(|a| a.unwrap()).collect()
+    } else {
+        m.cols().map(|col| {
+            col.iter().enumerate().fold(init.clone(), |a, (i, x)| f(a, i, x))
+        }).collect()
+    }
+}
+
+/// Position of the element that `better` prefers, ties go to the first element in row-major order
+fn arg<T, F>(m: &::strided::Mat<T>, better: F) -> Option<(usize, usize)> where
+    F: Fn(&T, &T) -> bool,
+    T: PartialOrd,
+{
+    let transposed = !rows_first(m);
+    let view = if transposed { m.t() } else { m };
+
+    let best = view.indexed_iter().fold(None, |best, ((i, j), x)| {
+        let pos = if transposed { (j, i) } else { (i, j) };
+
+        match best {
+            _ if !comparable(x) => best,
+            Some((best_pos, best)) if !(better(x, best) || (x == best && pos < best_pos)) => {
+                Some((best_pos, best))
+            },
+            _ => Some((pos, x)),
+        }
+    });
+
+    best.map(|(pos, _)| pos)
+}
+
+/// Per-axis version of `arg`, ties go to the first position along the axis
+///
+/// An axis without any comparable element reduces to the position `0`
+fn arg_axis<T, F>(m: &::strided::Mat<T>, axis: Axis, better: F) -> Vec<usize> where
+    F: Fn(&T, &T) -> bool,
+    T: PartialOrd,
+{
+    assert!(m.reduced_len(axis) != 0);
+
+    let best = fold_axis(m, axis, None, |best, i, x| match best {
+        _ if !comparable(x) => best,
+        Some((best_i, best)) if !better(x, best) => Some((best_i, best)),
+        _ => Some((i, x)),
+    });
+
+    best.into_iter().map(|best| best.map_or(0, |best| best.0)).collect()
+}
+
+/// The reduced values as a row (`Axis::Rows`) or a column (`Axis::Cols`)
+fn to_mat<T>(elems: Vec<T>, axis: Axis) -> Box<::Mat<T>> {
+    let n = elems.len();
+
+    match axis {
+        Axis::Rows => ::Mat::from_vec(elems, (1, n)),
+        Axis::Cols => ::Mat::from_vec(elems, (n, 1)),
+    }
+}
+
+/// Welford's online algorithm for the mean and the variance
+#[derive(Clone, Copy)]
+struct Welford<T> {
+    n: usize,
+    mean: T,
+    /// Sum of the squared differences from the mean
+    m2: T,
+}
+
+impl<T> Welford<T> where T: Float {
+    fn new() -> Welford<T> {
+        Welford {
+            n: 0,
+            mean: T::zero(),
+            m2: T::zero(),
+        }
+    }
+
+    fn push(self, x: T) -> Welford<T> {
+        let n = self.n + 1;
+        let delta = x - self.mean;
+        let mean = self.mean + delta / T::from_usize(n);
+
+        Welford {
+            n: n,
+            mean: mean,
+            m2: self.m2 + delta * (x - mean),
+        }
+    }
+
+    fn var(&self) -> T {
+        self.m2 / T::from_usize(self.n)
+    }
+}
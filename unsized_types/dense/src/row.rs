@@ -1,12 +1,139 @@
-use std::mem;
-use std::ops::Index;
+use std::marker::Unsized;
+use std::ops::{Index, IndexMut};
+use std::raw::FatPtr;
+use std::{fat_ptr, fmt, mem};
+
+use strided::col::Info;
+use strided::iter::{Iter, IterMut};
+
+/// A row has the same in-memory representation as a strided column: the distance between two
+/// consecutive elements is `stride`. The row of a row-major matrix has `stride = 1`, and the row
+/// of a transposed view has `stride` equal to the row stride of the original matrix.
+impl<T> ::Row<T> {
+    pub fn repr(&self) -> FatPtr<T, Info> {
+        fat_ptr::repr(self)
+    }
+
+    /// Returns the `i`-th element, or `None` if `i` is out of bounds
+    pub fn get(&self, i: usize) -> Option<&T> {
+        let FatPtr { data, info } = self.repr();
+
+        if i < info.len {
+            unsafe {
+                Some(&*data.offset(i as isize * info.stride))
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Mutable version of `get`
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        let FatPtr { data, info } = self.repr();
+
+        if i < info.len {
+            unsafe {
+                Some(&mut *data.offset(i as isize * info.stride))
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Views the row as a `1 x n` strided matrix
+    pub fn as_mat(&self) -> &::strided::Mat<T> {
+        let FatPtr { data, info } = self.repr();
+
+        unsafe {
+            &*fat_ptr::new(FatPtr { data: data, info: as_mat(info) })
+        }
+    }
+
+    /// Mutable version of `as_mat`
+    pub fn as_mat_mut(&mut self) -> &mut ::strided::Mat<T> {
+        let FatPtr { data, info } = self.repr();
+
+        unsafe {
+            &mut *fat_ptr::new(FatPtr { data: data, info: as_mat(info) })
+        }
+    }
+
+    /// Returns an iterator over the elements of the row
+    pub fn iter(&self) -> Iter<T> {
+        let FatPtr { data, info } = self.repr();
+
+        unsafe {
+            Iter::new(data, as_mat(info))
+        }
+    }
+
+    /// Returns an iterator over the mutable elements of the row
+    pub fn iter_mut(&mut self) -> IterMut<T> {
+        let FatPtr { data, info } = self.repr();
+
+        unsafe {
+            IterMut::new(data, as_mat(info))
+        }
+    }
+}
+
+/// A row is a strided matrix with a single row
+fn as_mat(info: Info) -> ::strided::mat::Info {
+    ::strided::mat::Info {
+        nrows: 1,
+        ncols: info.len,
+        row_stride: info.len as isize * info.stride,
+        col_stride: info.stride,
+    }
+}
+
+impl<T> fmt::Debug for ::Row<T> where T: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        try!(f.write_str("Row(["));
+
+        for (i, x) in self.iter().enumerate() {
+            if i != 0 {
+                try!(f.write_str(", "));
+            }
+
+            try!(write!(f, "{:?}", x))
+        }
+
+        f.write_str("])")
+    }
+}
 
 impl<T> Index<usize> for ::Row<T> {
     type Output = T;
 
     fn index(&self, i: usize) -> &T {
+        let FatPtr { data, info } = self.repr();
+
+        assert!(i < info.len);
+
         unsafe {
-            &mem::transmute::<_, &[T]>(self)[i]
+            &*data.offset(i as isize * info.stride)
         }
     }
 }
+
+impl<T> IndexMut<usize> for ::Row<T> {
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        let FatPtr { data, info } = self.repr();
+
+        assert!(i < info.len);
+
+        unsafe {
+            &mut *data.offset(i as isize * info.stride)
+        }
+    }
+}
+
+impl<T> Unsized for ::Row<T> {
+    type Data = T;
+    type Info = Info;
+
+    fn size_of_val(info: Info) -> usize {
+        info.len * info.stride.abs() as usize * mem::size_of::<T>()
+    }
+}
@@ -1,36 +1,134 @@
 use std::marker::Unsized;
+use std::ops::{Index, IndexMut};
 use std::raw::FatPtr;
 use std::{fat_ptr, fmt, mem};
 
-#[derive(Clone, Copy)]
+use strided::iter::{Iter, IterMut};
+
+#[derive(Clone, Copy, Debug)]
 pub struct Info {
+    /// Number of elements
     pub len: usize,
-    pub stride: usize,
+    /// Distance between two consecutive elements, negative if the view is reversed
+    pub stride: isize,
 }
 
 impl<T> ::strided::Col<T> {
     pub fn repr(&self) -> FatPtr<T, Info> {
         fat_ptr::repr(self)
     }
+
+    /// Returns the `i`-th element, or `None` if `i` is out of bounds
+    pub fn get(&self, i: usize) -> Option<&T> {
+        let FatPtr { data, info } = self.repr();
+
+        if i < info.len {
+            unsafe {
+                Some(&*data.offset(i as isize * info.stride))
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Mutable version of `get`
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut T> {
+        let FatPtr { data, info } = self.repr();
+
+        if i < info.len {
+            unsafe {
+                Some(&mut *data.offset(i as isize * info.stride))
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Views the column as a `n x 1` strided matrix
+    pub fn as_mat(&self) -> &::strided::Mat<T> {
+        let FatPtr { data, info } = self.repr();
+
+        unsafe {
+            &*fat_ptr::new(FatPtr { data: data, info: as_mat(info) })
+        }
+    }
+
+    /// Mutable version of `as_mat`
+    pub fn as_mat_mut(&mut self) -> &mut ::strided::Mat<T> {
+        let FatPtr { data, info } = self.repr();
+
+        unsafe {
+            &mut *fat_ptr::new(FatPtr { data: data, info: as_mat(info) })
+        }
+    }
+
+    /// Returns an iterator over the elements of the column
+    pub fn iter(&self) -> Iter<T> {
+        let FatPtr { data, info } = self.repr();
+
+        unsafe {
+            Iter::new(data, as_mat(info))
+        }
+    }
+
+    /// Returns an iterator over the mutable elements of the column
+    pub fn iter_mut(&mut self) -> IterMut<T> {
+        let FatPtr { data, info } = self.repr();
+
+        unsafe {
+            IterMut::new(data, as_mat(info))
+        }
+    }
+}
+
+/// A column is a strided matrix with a single column
+fn as_mat(info: Info) -> ::strided::mat::Info {
+    ::strided::mat::Info {
+        nrows: info.len,
+        ncols: 1,
+        row_stride: info.stride,
+        col_stride: 1,
+    }
 }
 
 impl<T> fmt::Debug for ::strided::Col<T> where T: fmt::Debug {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        unsafe {
-            let FatPtr { data, info } = self.repr();
+        try!(f.write_str("Col(["));
 
-            try!(f.write_str("Col(["));
+        for (i, x) in self.iter().enumerate() {
+            if i != 0 {
+                try!(f.write_str(", "));
+            }
 
-            // NB(japaric) this should use iterators, but I don't want to make example bigger
-            for i in 0..info.len {
-                if i != 0 {
-                    try!(f.write_str(", "));
-                }
+            try!(write!(f, "{:?}", x))
+        }
 
-                try!(write!(f, "{:?}", *data.offset((i * info.stride) as isize)))
-            }
+        f.write_str("])")
+    }
+}
+
+impl<T> Index<usize> for ::strided::Col<T> {
+    type Output = T;
+
+    fn index(&self, i: usize) -> &T {
+        let FatPtr { data, info } = self.repr();
+
+        assert!(i < info.len);
+
+        unsafe {
+            &*data.offset(i as isize * info.stride)
+        }
+    }
+}
 
-            f.write_str("])")
+impl<T> IndexMut<usize> for ::strided::Col<T> {
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        let FatPtr { data, info } = self.repr();
+
+        assert!(i < info.len);
+
+        unsafe {
+            &mut *data.offset(i as isize * info.stride)
         }
     }
 }
@@ -40,6 +138,6 @@ impl<T> Unsized for ::strided::Col<T> {
     type Info = Info;
 
     fn size_of_val(info: Info) -> usize {
-        info.len * info.stride * mem::size_of::<T>()
+        info.len * info.stride.abs() as usize * mem::size_of::<T>()
     }
 }
@@ -0,0 +1,147 @@
+//! Indices accepted by a strided matrix: `usize` (row), `(usize, usize)` (element), and pairs
+//! where one or both of the components are `Slice`s (see the `range` module)
+
+use std::fat_ptr;
+use std::raw::FatPtr;
+
+use error::Error;
+use range::{Indices, Slice};
+
+/// An index into a strided matrix
+pub trait MatIndex<T> {
+    /// What the index points to: an element, a row, a column or a submatrix
+    type Output: ?Sized;
+
+    /// Returns a pointer to the indexed element/view, or which bound was violated
+    ///
+    /// NB This hands out a raw pointer, the caller must tie it to the right borrow of `m`
+    fn get_raw(self, m: &::strided::Mat<T>) -> Result<*mut Self::Output, Error>;
+}
+
+fn check_col(col: usize, ncols: usize) -> Result<(), Error> {
+    if col < ncols {
+        Ok(())
+    } else {
+        Err(Error::ColOutOfBounds { col: col, ncols: ncols })
+    }
+}
+
+fn check_row(row: usize, nrows: usize) -> Result<(), Error> {
+    if row < nrows {
+        Ok(())
+    } else {
+        Err(Error::RowOutOfBounds { row: row, nrows: nrows })
+    }
+}
+
+fn resolve_cols<C>(col: C, ncols: usize) -> Result<Indices, Error> where C: Slice {
+    col.try_resolve(ncols).map_err(|r| {
+        Error::ColRangeOutOfBounds { start: r.start, end: r.end, ncols: ncols }
+    })
+}
+
+fn resolve_rows<R>(row: R, nrows: usize) -> Result<Indices, Error> where R: Slice {
+    row.try_resolve(nrows).map_err(|r| {
+        Error::RowRangeOutOfBounds { start: r.start, end: r.end, nrows: nrows }
+    })
+}
+
+/// Row: `&m[1]`
+impl<T> MatIndex<T> for usize {
+    type Output = ::Row<T>;
+
+    fn get_raw(self, m: &::strided::Mat<T>) -> Result<*mut ::Row<T>, Error> {
+        (self, ..).get_raw(m)
+    }
+}
+
+/// Element: `m[(1, 2)]`
+impl<T> MatIndex<T> for (usize, usize) {
+    type Output = T;
+
+    fn get_raw(self, m: &::strided::Mat<T>) -> Result<*mut T, Error> {
+        let FatPtr { data, info } = m.repr();
+        let (row, col) = self;
+
+        try!(check_row(row, info.nrows));
+        try!(check_col(col, info.ncols));
+
+        unsafe {
+            Ok(data.offset(row as isize * info.row_stride + col as isize * info.col_stride))
+        }
+    }
+}
+
+/// (Part of a) row: `&m[(1, ..)]`, `&m[(1, 2..)]`
+impl<T, C> MatIndex<T> for (usize, C) where C: Slice {
+    type Output = ::Row<T>;
+
+    fn get_raw(self, m: &::strided::Mat<T>) -> Result<*mut ::Row<T>, Error> {
+        let FatPtr { data, info } = m.repr();
+        let (row, col) = self;
+
+        try!(check_row(row, info.nrows));
+        let col = try!(resolve_cols(col, info.ncols));
+        let offset = row as isize * info.row_stride + col.start as isize * info.col_stride;
+
+        unsafe {
+            Ok(fat_ptr::new(FatPtr {
+                data: data.offset(offset),
+                info: ::strided::col::Info {
+                    len: col.len,
+                    stride: info.col_stride * col.step,
+                }
+            }))
+        }
+    }
+}
+
+/// (Part of a) column: `&m[(.., 2)]`, `&m[((..).step(-1), 2)]`
+impl<T, R> MatIndex<T> for (R, usize) where R: Slice {
+    type Output = ::strided::Col<T>;
+
+    fn get_raw(self, m: &::strided::Mat<T>) -> Result<*mut ::strided::Col<T>, Error> {
+        let FatPtr { data, info } = m.repr();
+        let (row, col) = self;
+
+        let row = try!(resolve_rows(row, info.nrows));
+        try!(check_col(col, info.ncols));
+        let offset = row.start as isize * info.row_stride + col as isize * info.col_stride;
+
+        unsafe {
+            Ok(fat_ptr::new(FatPtr {
+                data: data.offset(offset),
+                info: ::strided::col::Info {
+                    len: row.len,
+                    stride: info.row_stride * row.step,
+                }
+            }))
+        }
+    }
+}
+
+/// Submatrix, the ranges may have a (negative) step: `&m[((..).step(2), (..).step(-1))]`
+impl<T, R, C> MatIndex<T> for (R, C) where R: Slice, C: Slice {
+    type Output = ::strided::Mat<T>;
+
+    fn get_raw(self, m: &::strided::Mat<T>) -> Result<*mut ::strided::Mat<T>, Error> {
+        let FatPtr { data, info } = m.repr();
+        let (row, col) = self;
+
+        let row = try!(resolve_rows(row, info.nrows));
+        let col = try!(resolve_cols(col, info.ncols));
+        let offset = row.start as isize * info.row_stride + col.start as isize * info.col_stride;
+
+        unsafe {
+            Ok(fat_ptr::new(FatPtr {
+                data: data.offset(offset),
+                info: ::strided::mat::Info {
+                    nrows: row.len,
+                    ncols: col.len,
+                    row_stride: info.row_stride * row.step,
+                    col_stride: info.col_stride * col.step,
+                }
+            }))
+        }
+    }
+}
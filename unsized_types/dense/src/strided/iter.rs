@@ -0,0 +1,486 @@
+//! Iterators over the rows, columns and elements of a strided matrix
+//!
+//! None of these iterators bound check the elements they yield: the bounds are checked once, when
+//! the iterator is created from a (valid) view.
+
+use std::fat_ptr;
+use std::marker::PhantomData;
+use std::raw::FatPtr;
+
+use strided::mat::Info;
+
+/// Row-major traversal of the positions of a matrix, from both ends
+#[derive(Clone, Copy)]
+struct Cursor {
+    /// Position of the last element that hasn't been yielded
+    back: (usize, usize),
+    /// Position of the first element that hasn't been yielded
+    front: (usize, usize),
+    /// Number of elements that haven't been yielded
+    len: usize,
+    ncols: usize,
+}
+
+impl Cursor {
+    fn new(info: Info) -> Cursor {
+        let len = info.nrows * info.ncols;
+
+        Cursor {
+            back: if len == 0 { (0, 0) } else { (info.nrows - 1, info.ncols - 1) },
+            front: (0, 0),
+            len: len,
+            ncols: info.ncols,
+        }
+    }
+
+    fn next(&mut self) -> Option<(usize, usize)> {
+        if self.len == 0 {
+            return None
+        }
+
+        let pos = self.front;
+        let (i, j) = pos;
+
+        self.len -= 1;
+        self.front = if j + 1 == self.ncols { (i + 1, 0) } else { (i, j + 1) };
+
+        Some(pos)
+    }
+
+    fn next_back(&mut self) -> Option<(usize, usize)> {
+        if self.len == 0 {
+            return None
+        }
+
+        let pos = self.back;
+        let (i, j) = pos;
+
+        self.len -= 1;
+        if self.len != 0 {
+            self.back = if j == 0 { (i - 1, self.ncols - 1) } else { (i, j - 1) };
+        }
+
+        Some(pos)
+    }
+}
+
+/// Iterator over the elements of a matrix, in row-major order
+pub struct Iter<'a, T: 'a> {
+    inner: IndexedIter<'a, T>,
+}
+
+impl<'a, T> Iter<'a, T> {
+    pub unsafe fn new(data: *const T, info: Info) -> Iter<'a, T> {
+        Iter {
+            inner: IndexedIter::new(data, info),
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.inner.next().map(|(_, x)| x)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        self.inner.next_back().map(|(_, x)| x)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+/// Iterator over the mutable elements of a matrix, in row-major order
+pub struct IterMut<'a, T: 'a> {
+    inner: IndexedIterMut<'a, T>,
+}
+
+impl<'a, T> IterMut<'a, T> {
+    pub unsafe fn new(data: *mut T, info: Info) -> IterMut<'a, T> {
+        IterMut {
+            inner: IndexedIterMut::new(data, info),
+        }
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        self.inner.next().map(|(_, x)| x)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut T> {
+        self.inner.next_back().map(|(_, x)| x)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {}
+
+/// Iterator over the elements of a matrix and their `(row, col)` position, in row-major order
+pub struct IndexedIter<'a, T: 'a> {
+    cursor: Cursor,
+    col_stride: isize,
+    data: *const T,
+    row_stride: isize,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> IndexedIter<'a, T> {
+    pub unsafe fn new(data: *const T, info: Info) -> IndexedIter<'a, T> {
+        IndexedIter {
+            col_stride: info.col_stride,
+            cursor: Cursor::new(info),
+            data: data,
+            row_stride: info.row_stride,
+            _marker: PhantomData,
+        }
+    }
+
+    unsafe fn get(&self, (i, j): (usize, usize)) -> ((usize, usize), &'a T) {
+        let offset = i as isize * self.row_stride + j as isize * self.col_stride;
+
+        ((i, j), &*self.data.offset(offset))
+    }
+}
+
+impl<'a, T> Iterator for IndexedIter<'a, T> {
+    type Item = ((usize, usize), &'a T);
+
+    fn next(&mut self) -> Option<((usize, usize), &'a T)> {
+        self.cursor.next().map(|pos| unsafe { self.get(pos) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.cursor.len, Some(self.cursor.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IndexedIter<'a, T> {
+    fn next_back(&mut self) -> Option<((usize, usize), &'a T)> {
+        self.cursor.next_back().map(|pos| unsafe { self.get(pos) })
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IndexedIter<'a, T> {}
+
+/// Iterator over the mutable elements of a matrix and their `(row, col)` position, in row-major
+/// order
+pub struct IndexedIterMut<'a, T: 'a> {
+    cursor: Cursor,
+    col_stride: isize,
+    data: *mut T,
+    row_stride: isize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> IndexedIterMut<'a, T> {
+    pub unsafe fn new(data: *mut T, info: Info) -> IndexedIterMut<'a, T> {
+        IndexedIterMut {
+            col_stride: info.col_stride,
+            cursor: Cursor::new(info),
+            data: data,
+            row_stride: info.row_stride,
+            _marker: PhantomData,
+        }
+    }
+
+    unsafe fn get(&self, (i, j): (usize, usize)) -> ((usize, usize), &'a mut T) {
+        let offset = i as isize * self.row_stride + j as isize * self.col_stride;
+
+        ((i, j), &mut *self.data.offset(offset))
+    }
+}
+
+impl<'a, T> Iterator for IndexedIterMut<'a, T> {
+    type Item = ((usize, usize), &'a mut T);
+
+    fn next(&mut self) -> Option<((usize, usize), &'a mut T)> {
+        self.cursor.next().map(|pos| unsafe { self.get(pos) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.cursor.len, Some(self.cursor.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IndexedIterMut<'a, T> {
+    fn next_back(&mut self) -> Option<((usize, usize), &'a mut T)> {
+        self.cursor.next_back().map(|pos| unsafe { self.get(pos) })
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IndexedIterMut<'a, T> {}
+
+/// Iterator over the rows of a matrix
+pub struct Rows<'a, T: 'a> {
+    back: usize,
+    data: *const T,
+    front: usize,
+    info: Info,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> Rows<'a, T> {
+    pub unsafe fn new(data: *const T, info: Info) -> Rows<'a, T> {
+        Rows {
+            back: info.nrows,
+            data: data,
+            front: 0,
+            info: info,
+            _marker: PhantomData,
+        }
+    }
+
+    unsafe fn get(&self, i: usize) -> &'a ::Row<T> {
+        &*fat_ptr::new(FatPtr {
+            data: self.data.offset(i as isize * self.info.row_stride) as *mut T,
+            info: ::strided::col::Info {
+                len: self.info.ncols,
+                stride: self.info.col_stride,
+            }
+        })
+    }
+}
+
+impl<'a, T> Iterator for Rows<'a, T> {
+    type Item = &'a ::Row<T>;
+
+    fn next(&mut self) -> Option<&'a ::Row<T>> {
+        if self.front == self.back {
+            None
+        } else {
+            self.front += 1;
+
+            Some(unsafe { self.get(self.front - 1) })
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Rows<'a, T> {
+    fn next_back(&mut self) -> Option<&'a ::Row<T>> {
+        if self.front == self.back {
+            None
+        } else {
+            self.back -= 1;
+
+            Some(unsafe { self.get(self.back) })
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Rows<'a, T> {}
+
+/// Iterator over the mutable rows of a matrix
+pub struct RowsMut<'a, T: 'a> {
+    back: usize,
+    data: *mut T,
+    front: usize,
+    info: Info,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> RowsMut<'a, T> {
+    pub unsafe fn new(data: *mut T, info: Info) -> RowsMut<'a, T> {
+        RowsMut {
+            back: info.nrows,
+            data: data,
+            front: 0,
+            info: info,
+            _marker: PhantomData,
+        }
+    }
+
+    unsafe fn get(&self, i: usize) -> &'a mut ::Row<T> {
+        &mut *fat_ptr::new(FatPtr {
+            data: self.data.offset(i as isize * self.info.row_stride),
+            info: ::strided::col::Info {
+                len: self.info.ncols,
+                stride: self.info.col_stride,
+            }
+        })
+    }
+}
+
+impl<'a, T> Iterator for RowsMut<'a, T> {
+    type Item = &'a mut ::Row<T>;
+
+    fn next(&mut self) -> Option<&'a mut ::Row<T>> {
+        if self.front == self.back {
+            None
+        } else {
+            self.front += 1;
+
+            Some(unsafe { self.get(self.front - 1) })
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for RowsMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut ::Row<T>> {
+        if self.front == self.back {
+            None
+        } else {
+            self.back -= 1;
+
+            Some(unsafe { self.get(self.back) })
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for RowsMut<'a, T> {}
+
+/// Iterator over the columns of a matrix
+pub struct Cols<'a, T: 'a> {
+    back: usize,
+    data: *const T,
+    front: usize,
+    info: Info,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T> Cols<'a, T> {
+    pub unsafe fn new(data: *const T, info: Info) -> Cols<'a, T> {
+        Cols {
+            back: info.ncols,
+            data: data,
+            front: 0,
+            info: info,
+            _marker: PhantomData,
+        }
+    }
+
+    unsafe fn get(&self, j: usize) -> &'a ::strided::Col<T> {
+        &*fat_ptr::new(FatPtr {
+            data: self.data.offset(j as isize * self.info.col_stride) as *mut T,
+            info: ::strided::col::Info {
+                len: self.info.nrows,
+                stride: self.info.row_stride,
+            }
+        })
+    }
+}
+
+impl<'a, T> Iterator for Cols<'a, T> {
+    type Item = &'a ::strided::Col<T>;
+
+    fn next(&mut self) -> Option<&'a ::strided::Col<T>> {
+        if self.front == self.back {
+            None
+        } else {
+            self.front += 1;
+
+            Some(unsafe { self.get(self.front - 1) })
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Cols<'a, T> {
+    fn next_back(&mut self) -> Option<&'a ::strided::Col<T>> {
+        if self.front == self.back {
+            None
+        } else {
+            self.back -= 1;
+
+            Some(unsafe { self.get(self.back) })
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Cols<'a, T> {}
+
+/// Iterator over the mutable columns of a matrix
+pub struct ColsMut<'a, T: 'a> {
+    back: usize,
+    data: *mut T,
+    front: usize,
+    info: Info,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> ColsMut<'a, T> {
+    pub unsafe fn new(data: *mut T, info: Info) -> ColsMut<'a, T> {
+        ColsMut {
+            back: info.ncols,
+            data: data,
+            front: 0,
+            info: info,
+            _marker: PhantomData,
+        }
+    }
+
+    unsafe fn get(&self, j: usize) -> &'a mut ::strided::Col<T> {
+        &mut *fat_ptr::new(FatPtr {
+            data: self.data.offset(j as isize * self.info.col_stride),
+            info: ::strided::col::Info {
+                len: self.info.nrows,
+                stride: self.info.row_stride,
+            }
+        })
+    }
+}
+
+impl<'a, T> Iterator for ColsMut<'a, T> {
+    type Item = &'a mut ::strided::Col<T>;
+
+    fn next(&mut self) -> Option<&'a mut ::strided::Col<T>> {
+        if self.front == self.back {
+            None
+        } else {
+            self.front += 1;
+
+            Some(unsafe { self.get(self.front - 1) })
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for ColsMut<'a, T> {
+    fn next_back(&mut self) -> Option<&'a mut ::strided::Col<T>> {
+        if self.front == self.back {
+            None
+        } else {
+            self.back -= 1;
+
+            Some(unsafe { self.get(self.back) })
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for ColsMut<'a, T> {}
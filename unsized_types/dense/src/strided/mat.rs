@@ -1,122 +1,474 @@
 use std::marker::Unsized;
-use std::ops::{Index, Range, RangeFull};
+use std::ops::{Index, IndexMut};
 use std::raw::FatPtr;
-use std::{fat_ptr, fmt, mem, slice};
+use std::{cmp, fat_ptr, fmt, mem, slice};
+
+use error::Error;
+use strided::index::MatIndex;
+use strided::iter::{Cols, ColsMut, IndexedIter, IndexedIterMut, Iter, IterMut, Rows, RowsMut};
 
 #[derive(Clone, Copy, Debug)]
 pub struct Info {
     pub nrows: usize,
     pub ncols: usize,
-    pub stride: usize,
+    /// Distance between the first elements of two consecutive rows
+    pub row_stride: isize,
+    /// Distance between two consecutive elements of a row
+    pub col_stride: isize,
+}
+
+impl Info {
+    /// Whether the rows are stored one after the other, with no gaps, in row-major order
+    ///
+    /// The stride of an axis of length one is irrelevant
+    fn is_contiguous(&self) -> bool {
+        (self.nrows <= 1 || self.row_stride == self.ncols as isize) &&
+            (self.ncols <= 1 || self.col_stride == 1)
+    }
 }
 
 impl<T> ::strided::Mat<T> {
     pub fn repr(&self) -> FatPtr<T, Info> {
         fat_ptr::repr(self)
     }
-}
 
-impl<T> fmt::Debug for ::strided::Mat<T> where T: fmt::Debug {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+    pub fn nrows(&self) -> usize {
+        self.repr().info.nrows
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.repr().info.ncols
+    }
+
+    /// Returns the `(nrows, ncols)` pair
+    pub fn size(&self) -> (usize, usize) {
+        let info = self.repr().info;
+
+        (info.nrows, info.ncols)
+    }
+
+    /// Returns the element, row, column or submatrix pointed to by `index`, or `None` if the
+    /// index is out of bounds
+    ///
+    /// This is the non-panicking version of `&m[index]`
+    pub fn get<I>(&self, index: I) -> Option<&I::Output> where I: MatIndex<T> {
+        self.try_get(index).ok()
+    }
+
+    /// Mutable version of `get`
+    pub fn get_mut<I>(&mut self, index: I) -> Option<&mut I::Output> where I: MatIndex<T> {
+        self.try_get_mut(index).ok()
+    }
+
+    /// Like `get`, but reports which bound was violated
+    pub fn try_get<I>(&self, index: I) -> Result<&I::Output, Error> where I: MatIndex<T> {
+        index.get_raw(self).map(|ptr| unsafe { &*ptr })
+    }
+
+    /// Mutable version of `try_get`
+    pub fn try_get_mut<I>(&mut self, index: I) -> Result<&mut I::Output, Error> where
+        I: MatIndex<T>,
+    {
+        index.get_raw(self).map(|ptr| unsafe { &mut *ptr })
+    }
+
+    /// Returns a transposed view of the matrix, no element is copied
+    pub fn t(&self) -> &::strided::Mat<T> {
         unsafe {
-            let FatPtr { data, info } = self.repr();
+            &*self.t_raw()
+        }
+    }
 
-            // NB(japaric) this should use iterators, but I don't want to make example bigger
-            for i in 0..info.nrows {
-                if i != 0 {
-                    try!(f.write_str("\n"));
-                }
+    /// Mutable version of `t`
+    pub fn t_mut(&mut self) -> &mut ::strided::Mat<T> {
+        unsafe {
+            &mut *self.t_raw()
+        }
+    }
 
-                let data = data.offset((i * info.stride) as isize);
-                let row = slice::from_raw_parts(data, info.ncols);
+    /// Returns the view as a contiguous matrix if its elements are stored in row-major order with
+    /// no gaps, i.e. if `row_stride == ncols` and `col_stride == 1`, or `None` otherwise
+    pub fn as_contiguous(&self) -> Option<&::Mat<T>> {
+        let FatPtr { data, info } = self.repr();
+
+        if info.is_contiguous() {
+            unsafe {
+                let slice = slice::from_raw_parts(data, info.nrows * info.ncols);
 
-                try!(write!(f, "{:?}", row))
+                Some(::Mat::reshape(slice, (info.nrows, info.ncols)))
             }
+        } else {
+            None
+        }
+    }
 
-            Ok(())
+    /// Mutable version of `as_contiguous`
+    pub fn as_contiguous_mut(&mut self) -> Option<&mut ::Mat<T>> {
+        let FatPtr { data, info } = self.repr();
+
+        if info.is_contiguous() {
+            unsafe {
+                let slice = slice::from_raw_parts_mut(data, info.nrows * info.ncols);
+
+                Some(::Mat::reshape_mut(slice, (info.nrows, info.ncols)))
+            }
+        } else {
+            None
+        }
+    }
+
+    /// Returns the main diagonal as a strided column
+    pub fn diag(&self) -> &::strided::Col<T> {
+        unsafe {
+            &*self.diag_raw(0)
+        }
+    }
+
+    /// Mutable version of `diag`
+    pub fn diag_mut(&mut self) -> &mut ::strided::Col<T> {
+        unsafe {
+            &mut *self.diag_raw(0)
+        }
+    }
+
+    /// Returns the `k`-th diagonal: the elements `(i, i + k)`
+    ///
+    /// `k > 0` selects a superdiagonal and `k < 0` a subdiagonal. The column is empty if the
+    /// diagonal falls outside of the matrix
+    pub fn diag_offset(&self, k: isize) -> &::strided::Col<T> {
+        unsafe {
+            &*self.diag_raw(k)
+        }
+    }
+
+    /// Mutable version of `diag_offset`
+    pub fn diag_offset_mut(&mut self, k: isize) -> &mut ::strided::Col<T> {
+        unsafe {
+            &mut *self.diag_raw(k)
+        }
+    }
+
+    /// Returns the anti-diagonal, which goes from the top-right corner towards the bottom-left
+    /// corner
+    pub fn anti_diag(&self) -> &::strided::Col<T> {
+        unsafe {
+            &*self.anti_diag_raw()
         }
     }
-}
 
-impl<T> Index<(Range<usize>, Range<usize>)> for ::strided::Mat<T> {
-    type Output = ::strided::Mat<T>;
+    /// Mutable version of `anti_diag`
+    pub fn anti_diag_mut(&mut self) -> &mut ::strided::Col<T> {
+        unsafe {
+            &mut *self.anti_diag_raw()
+        }
+    }
 
-    fn index(&self, (row, col): (Range<usize>, Range<usize>)) -> &::strided::Mat<T> {
+    /// Returns an iterator over the rows of the matrix
+    pub fn rows(&self) -> Rows<T> {
         let FatPtr { data, info } = self.repr();
 
-        assert!(row.start <= row.end);
-        assert!(row.end <= info.nrows);
-        assert!(col.start <= col.end);
-        assert!(col.end <= info.ncols);
+        unsafe {
+            Rows::new(data, info)
+        }
+    }
+
+    /// Returns an iterator over the mutable rows of the matrix
+    pub fn rows_mut(&mut self) -> RowsMut<T> {
+        let FatPtr { data, info } = self.repr();
 
         unsafe {
-            &*fat_ptr::new(FatPtr {
-                data: data.offset((row.start * info.stride + col.start) as isize),
-                info: Info {
-                    nrows: row.end - row.start,
-                    ncols: col.end - col.start,
-                    stride: info.stride,
-                }
-            })
+            RowsMut::new(data, info)
         }
     }
-}
 
-impl<T> Index<(RangeFull, usize)> for ::strided::Mat<T> {
-    type Output = ::strided::Col<T>;
+    /// Returns an iterator over the columns of the matrix
+    pub fn cols(&self) -> Cols<T> {
+        let FatPtr { data, info } = self.repr();
 
-    fn index(&self, (_, col): (RangeFull, usize)) -> &::strided::Col<T> {
+        unsafe {
+            Cols::new(data, info)
+        }
+    }
+
+    /// Returns an iterator over the mutable columns of the matrix
+    pub fn cols_mut(&mut self) -> ColsMut<T> {
         let FatPtr { data, info } = self.repr();
 
-        assert!(col < info.ncols);
+        unsafe {
+            ColsMut::new(data, info)
+        }
+    }
+
+    /// Returns an iterator over the elements of the matrix, in row-major order
+    pub fn iter(&self) -> Iter<T> {
+        let FatPtr { data, info } = self.repr();
 
         unsafe {
-            &*fat_ptr::new(FatPtr {
-                data: data.offset(col as isize),
-                info: ::strided::col::Info {
-                    len: info.nrows,
-                    stride: info.stride,
-                }
-            })
+            Iter::new(data, info)
         }
     }
-}
 
-impl<T> Index<(usize, usize)> for ::strided::Mat<T> {
-    type Output = T;
+    /// Returns an iterator over the mutable elements of the matrix, in row-major order
+    pub fn iter_mut(&mut self) -> IterMut<T> {
+        let FatPtr { data, info } = self.repr();
+
+        unsafe {
+            IterMut::new(data, info)
+        }
+    }
 
-    fn index(&self, (row, col): (usize, usize)) -> &T {
+    /// Returns an iterator over the elements of the matrix and their `(row, col)` position, in
+    /// row-major order
+    pub fn indexed_iter(&self) -> IndexedIter<T> {
         let FatPtr { data, info } = self.repr();
 
-        assert!(row < info.nrows && col < info.ncols);
+        unsafe {
+            IndexedIter::new(data, info)
+        }
+    }
+
+    /// Mutable version of `indexed_iter`
+    pub fn indexed_iter_mut(&mut self) -> IndexedIterMut<T> {
+        let FatPtr { data, info } = self.repr();
 
         unsafe {
-            &*data.offset((row * info.stride + col) as isize)
+            IndexedIterMut::new(data, info)
         }
     }
-}
 
-impl<T> Index<usize> for ::strided::Mat<T> {
-    type Output = ::Row<T>;
+    /// Divides the matrix into two at a row index
+    ///
+    /// The first half contains the rows `[0, row)` and the second half the rows `[row, nrows)`
+    pub fn split_at_row(&self, row: usize) -> (&::strided::Mat<T>, &::strided::Mat<T>) {
+        let info = self.repr().info;
+
+        assert!(row <= info.nrows);
+
+        unsafe {
+            (&*self.index_raw((0..row, 0..info.ncols)),
+             &*self.index_raw((row..info.nrows, 0..info.ncols)))
+        }
+    }
+
+    /// Mutable version of `split_at_row`
+    pub fn split_at_row_mut(
+        &mut self,
+        row: usize,
+    ) -> (&mut ::strided::Mat<T>, &mut ::strided::Mat<T>) {
+        let info = self.repr().info;
+
+        assert!(row <= info.nrows);
 
-    fn index(&self, row: usize) -> &::Row<T> {
         unsafe {
-            let FatPtr { data, info } = self.repr();
+            (&mut *self.index_raw((0..row, 0..info.ncols)),
+             &mut *self.index_raw((row..info.nrows, 0..info.ncols)))
+        }
+    }
 
-            assert!(row < info.nrows);
+    /// Divides the matrix into two at a column index
+    ///
+    /// The first half contains the columns `[0, col)` and the second half the columns
+    /// `[col, ncols)`
+    pub fn split_at_col(&self, col: usize) -> (&::strided::Mat<T>, &::strided::Mat<T>) {
+        let info = self.repr().info;
 
-            let data = data.offset((row * info.stride) as isize);
+        assert!(col <= info.ncols);
 
-            mem::transmute(slice::from_raw_parts(data, info.ncols))
+        unsafe {
+            (&*self.index_raw((0..info.nrows, 0..col)),
+             &*self.index_raw((0..info.nrows, col..info.ncols)))
         }
     }
+
+    /// Mutable version of `split_at_col`
+    pub fn split_at_col_mut(
+        &mut self,
+        col: usize,
+    ) -> (&mut ::strided::Mat<T>, &mut ::strided::Mat<T>) {
+        let info = self.repr().info;
+
+        assert!(col <= info.ncols);
+
+        unsafe {
+            (&mut *self.index_raw((0..info.nrows, 0..col)),
+             &mut *self.index_raw((0..info.nrows, col..info.ncols)))
+        }
+    }
+
+    /// Divides the matrix into four quadrants at the `(row, col)` intersection
+    ///
+    /// The quadrants are returned in the top-left, top-right, bottom-left, bottom-right order
+    pub fn split_quad(&self, (row, col): (usize, usize)) -> (
+        &::strided::Mat<T>,
+        &::strided::Mat<T>,
+        &::strided::Mat<T>,
+        &::strided::Mat<T>,
+    ) {
+        let info = self.repr().info;
+
+        assert!(row <= info.nrows);
+        assert!(col <= info.ncols);
+
+        unsafe {
+            (&*self.index_raw((0..row, 0..col)),
+             &*self.index_raw((0..row, col..info.ncols)),
+             &*self.index_raw((row..info.nrows, 0..col)),
+             &*self.index_raw((row..info.nrows, col..info.ncols)))
+        }
+    }
+
+    /// Mutable version of `split_quad`
+    pub fn split_quad_mut(&mut self, (row, col): (usize, usize)) -> (
+        &mut ::strided::Mat<T>,
+        &mut ::strided::Mat<T>,
+        &mut ::strided::Mat<T>,
+        &mut ::strided::Mat<T>,
+    ) {
+        let info = self.repr().info;
+
+        assert!(row <= info.nrows);
+        assert!(col <= info.ncols);
+
+        unsafe {
+            (&mut *self.index_raw((0..row, 0..col)),
+             &mut *self.index_raw((0..row, col..info.ncols)),
+             &mut *self.index_raw((row..info.nrows, 0..col)),
+             &mut *self.index_raw((row..info.nrows, col..info.ncols)))
+        }
+    }
+
+    /// Panicking version of `MatIndex::get_raw`
+    fn index_raw<I>(&self, index: I) -> *mut I::Output where I: MatIndex<T> {
+        match index.get_raw(self) {
+            Ok(ptr) => ptr,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// The `k`-th diagonal, a column whose stride is the sum of both strides
+    unsafe fn diag_raw(&self, k: isize) -> *mut ::strided::Col<T> {
+        let FatPtr { data, info } = self.repr();
+        let (nrows, ncols) = (info.nrows as isize, info.ncols as isize);
+        // First element and length of the diagonal
+        let (row, col) = if k >= 0 { (0, k) } else { (-k, 0) };
+        let len = cmp::max(cmp::min(nrows - row, ncols - col), 0);
+        let offset = if len == 0 { 0 } else { row * info.row_stride + col * info.col_stride };
+
+        fat_ptr::new(FatPtr {
+            data: data.offset(offset),
+            info: ::strided::col::Info {
+                len: len as usize,
+                stride: info.row_stride + info.col_stride,
+            }
+        })
+    }
+
+    /// The anti-diagonal starts at the last column and walks the columns backwards
+    unsafe fn anti_diag_raw(&self) -> *mut ::strided::Col<T> {
+        let FatPtr { data, info } = self.repr();
+        let len = cmp::min(info.nrows, info.ncols);
+        let offset = if len == 0 { 0 } else { (info.ncols - 1) as isize * info.col_stride };
+
+        fat_ptr::new(FatPtr {
+            data: data.offset(offset),
+            info: ::strided::col::Info {
+                len: len,
+                stride: info.row_stride - info.col_stride,
+            }
+        })
+    }
+
+    /// Transposed view
+    unsafe fn t_raw(&self) -> *mut ::strided::Mat<T> {
+        let FatPtr { data, info } = self.repr();
+
+        fat_ptr::new(FatPtr {
+            data: data,
+            info: Info {
+                nrows: info.ncols,
+                ncols: info.nrows,
+                row_stride: info.col_stride,
+                col_stride: info.row_stride,
+            }
+        })
+    }
+}
+
+impl<T> fmt::Debug for ::strided::Mat<T> where T: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i != 0 {
+                try!(f.write_str("\n"));
+            }
+
+            try!(f.write_str("["));
+
+            for (j, x) in row.iter().enumerate() {
+                if j != 0 {
+                    try!(f.write_str(", "));
+                }
+
+                try!(write!(f, "{:?}", x))
+            }
+
+            try!(f.write_str("]"))
+        }
+
+        Ok(())
+    }
+}
+
+/// See the `strided::index` module for the accepted indices
+impl<T, I> Index<I> for ::strided::Mat<T> where I: MatIndex<T> {
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &I::Output {
+        unsafe {
+            &*self.index_raw(index)
+        }
+    }
+}
+
+impl<T, I> IndexMut<I> for ::strided::Mat<T> where I: MatIndex<T> {
+    fn index_mut(&mut self, index: I) -> &mut I::Output {
+        unsafe {
+            &mut *self.index_raw(index)
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a ::strided::Mat<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut ::strided::Mat<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
 }
 
 impl<T> Unsized for ::strided::Mat<T> {
     type Data = T;
     type Info = Info;
 
+    /// The span of memory between the first and the last element
     fn size_of_val(info: Info) -> usize {
-        info.nrows * info.stride * mem::size_of::<T>()
+        if info.nrows == 0 || info.ncols == 0 {
+            0
+        } else {
+            let row_span = (info.nrows - 1) * info.row_stride.abs() as usize;
+            let col_span = (info.ncols - 1) * info.col_stride.abs() as usize;
+            let last = row_span + col_span;
+
+            (last + 1) * mem::size_of::<T>()
+        }
     }
 }
@@ -1,5 +1,6 @@
-mod col;
-
+pub mod col;
+pub mod index;
+pub mod iter;
 pub mod mat;
 
 /// A view into the column of a matrix
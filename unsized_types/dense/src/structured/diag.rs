@@ -0,0 +1,144 @@
+use std::marker::Unsized;
+use std::num::Zero;
+use std::ops::{Index, IndexMut, Mul};
+use std::raw::FatPtr;
+use std::{fat_ptr, fmt};
+
+use error::Error;
+use num::{Scalar, StaticZero};
+use ops::AsStrided;
+use strided::col::Info;
+
+impl<T> ::structured::Diag<T> {
+    pub fn repr(&self) -> FatPtr<T, Info> {
+        fat_ptr::repr(self)
+    }
+
+    /// Returns the order of the matrix
+    pub fn n(&self) -> usize {
+        self.repr().info.len
+    }
+
+    /// Returns the diagonal elements as a strided column
+    pub fn as_col(&self) -> &::strided::Col<T> {
+        unsafe {
+            &*fat_ptr::new(self.repr())
+        }
+    }
+
+    /// Mutable version of `as_col`
+    pub fn as_col_mut(&mut self) -> &mut ::strided::Col<T> {
+        unsafe {
+            &mut *fat_ptr::new(self.repr())
+        }
+    }
+
+    /// Returns the diagonal element at `(i, j)`, or `None` if the index is out of bounds or off
+    /// the diagonal
+    ///
+    /// This is the non-panicking version of `&mut d[(i, j)]`
+    pub fn get_mut(&mut self, index: (usize, usize)) -> Option<&mut T> {
+        self.try_get_mut(index).ok()
+    }
+
+    /// Like `get_mut`, but reports why the element can't be written
+    pub fn try_get_mut(&mut self, (i, j): (usize, usize)) -> Result<&mut T, Error> {
+        try!(self.check_bounds((i, j)));
+
+        if i == j {
+            Ok(&mut self.as_col_mut()[i])
+        } else {
+            Err(Error::OutsideStructure { row: i, col: j })
+        }
+    }
+
+    /// Returns the out of bounds error if `(i, j)` is not in the matrix
+    fn check_bounds(&self, (i, j): (usize, usize)) -> Result<(), Error> {
+        let n = self.n();
+
+        if i >= n {
+            Err(Error::RowOutOfBounds { row: i, nrows: n })
+        } else if j >= n {
+            Err(Error::ColOutOfBounds { col: j, ncols: n })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<T> fmt::Debug for ::structured::Diag<T> where T: fmt::Debug + Zero {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let d = self.as_col();
+
+        ::structured::fmt_logical(f, self.n(), |i, j| if i == j { Some(&d[i]) } else { None })
+    }
+}
+
+/// The off-diagonal elements read as zero
+impl<T> Index<(usize, usize)> for ::structured::Diag<T> where T: StaticZero {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        if let Err(e) = self.check_bounds((i, j)) {
+            panic!("{}", e)
+        }
+
+        if i == j { &self.as_col()[i] } else { T::zero_ref() }
+    }
+}
+
+/// Panics with `Error::OutsideStructure` if the element is not on the diagonal
+impl<T> IndexMut<(usize, usize)> for ::structured::Diag<T> where T: StaticZero {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut T {
+        match self.try_get_mut(index) {
+            Ok(x) => x,
+            Err(e) => panic!("{}", e),
+        }
+    }
+}
+
+/// Scales the rows of the right operand
+impl<'a, T, R> Mul<R> for &'a ::structured::Diag<T> where R: AsStrided<T>, T: Scalar {
+    type Output = Box<::Mat<T>>;
+
+    fn mul(self, rhs: R) -> Box<::Mat<T>> {
+        let (d, b) = (self.as_col(), rhs.as_strided());
+
+        ::structured::check_inner(self.n(), b);
+
+        ::Mat::from_fn(b.size(), |i, j| d[i] * b[(i, j)])
+    }
+}
+
+/// Matrix times diagonal: scales the columns of the left operand
+macro_rules! mul {
+    ($($lhs:ty),+) => {
+        $(
+            impl<'a, 'b, T> Mul<&'b ::structured::Diag<T>> for $lhs where T: Scalar {
+                type Output = Box<::Mat<T>>;
+
+                fn mul(self, rhs: &'b ::structured::Diag<T>) -> Box<::Mat<T>> {
+                    let (a, d) = (self.as_strided(), rhs.as_col());
+                    let n = rhs.n();
+
+                    if a.ncols() != n {
+                        panic!("{}", Error::InnerSizeMismatch { lhs: a.size(), rhs: (n, n) })
+                    }
+
+                    ::Mat::from_fn(a.size(), |i, j| a[(i, j)] * d[j])
+                }
+            }
+        )+
+    }
+}
+
+mul!(&'a ::strided::Mat<T>, &'a ::Mat<T>, &'a ::ColMajor<T>, &'a Box<::Mat<T>>, Box<::Mat<T>>);
+
+impl<T> Unsized for ::structured::Diag<T> {
+    type Data = T;
+    type Info = Info;
+
+    fn size_of_val(info: Info) -> usize {
+        <::strided::Col<T> as Unsized>::size_of_val(info)
+    }
+}
@@ -0,0 +1,155 @@
+//! Views that give structure to a square matrix: triangular, symmetric and diagonal
+//!
+//! The views are obtained from a square `strided::Mat` without copying any element, e.g.
+//! `m.as_lower()`. Indexing an element that is not stored returns what that element logically
+//! is: zero for the triangular and diagonal views, the mirrored element for the symmetric view.
+//! Writing to a zero element panics with `Error::OutsideStructure`, `try_get_mut` reports it
+//! instead.
+//!
+//! The product of a view and a matrix only reads the stored part of the view, and is blocked so
+//! that most of the work is done by `gemm`.
+
+use std::marker::Unsized;
+use std::num::Zero;
+use std::raw::FatPtr;
+use std::{fat_ptr, fmt};
+
+use error::Error;
+use strided::mat::Info;
+
+mod diag;
+mod symmetric;
+mod triangular;
+
+/// Order of the diagonal blocks of the products, which are multiplied row by row; the other
+/// blocks go through `gemm`
+const NB: usize = 64;
+
+/// The diagonal of a square matrix, the off-diagonal elements are zero
+pub unsized type Diag<T>;
+
+/// The lower triangle of a square matrix, the strictly upper triangle is zero
+pub unsized type Lower<T>;
+
+/// A symmetric matrix stored in the lower triangle of a square matrix
+pub unsized type Symmetric<T>;
+
+/// The upper triangle of a square matrix, the strictly lower triangle is zero
+pub unsized type Upper<T>;
+
+/// These views panic with `Error::NotSquare` if the matrix is not square
+impl<T> ::strided::Mat<T> {
+    /// Views the diagonal of the matrix
+    pub fn as_diag(&self) -> &Diag<T> {
+        unsafe {
+            &*self.as_diag_raw()
+        }
+    }
+
+    /// Mutable version of `as_diag`
+    pub fn as_diag_mut(&mut self) -> &mut Diag<T> {
+        unsafe {
+            &mut *self.as_diag_raw()
+        }
+    }
+
+    /// Views the lower triangle of the matrix
+    pub fn as_lower(&self) -> &Lower<T> {
+        unsafe {
+            &*self.square_raw()
+        }
+    }
+
+    /// Mutable version of `as_lower`
+    pub fn as_lower_mut(&mut self) -> &mut Lower<T> {
+        unsafe {
+            &mut *self.square_raw()
+        }
+    }
+
+    /// Views the matrix as a symmetric matrix, only its lower triangle is used
+    pub fn as_symmetric(&self) -> &Symmetric<T> {
+        unsafe {
+            &*self.square_raw()
+        }
+    }
+
+    /// Mutable version of `as_symmetric`
+    pub fn as_symmetric_mut(&mut self) -> &mut Symmetric<T> {
+        unsafe {
+            &mut *self.square_raw()
+        }
+    }
+
+    /// Views the upper triangle of the matrix
+    pub fn as_upper(&self) -> &Upper<T> {
+        unsafe {
+            &*self.square_raw()
+        }
+    }
+
+    /// Mutable version of `as_upper`
+    pub fn as_upper_mut(&mut self) -> &mut Upper<T> {
+        unsafe {
+            &mut *self.square_raw()
+        }
+    }
+
+    /// The diagonal view has the same representation as the main diagonal
+    unsafe fn as_diag_raw(&self) -> *mut Diag<T> {
+        check_square(self.repr().info);
+
+        fat_ptr::new(self.diag().repr())
+    }
+
+    /// The triangular and symmetric views have the same representation as the matrix
+    unsafe fn square_raw<U: ?Sized>(&self) -> *mut U where U: Unsized<Data=T, Info=Info> {
+        let FatPtr { data, info } = self.repr();
+
+        check_square(info);
+
+        fat_ptr::new(FatPtr { data: data, info: info })
+    }
+}
+
+fn check_square(info: Info) {
+    if info.nrows != info.ncols {
+        panic!("{}", Error::NotSquare { size: (info.nrows, info.ncols) })
+    }
+}
+
+/// Panics with `Error::InnerSizeMismatch` if the `n x n` view can't multiply `rhs`
+fn check_inner<T>(n: usize, rhs: &::strided::Mat<T>) {
+    if n != rhs.nrows() {
+        panic!("{}", Error::InnerSizeMismatch { lhs: (n, n), rhs: rhs.size() })
+    }
+}
+
+/// Prints the logical `n x n` matrix in the same format as `strided::Mat`, `elem(i, j)` is
+/// `None` for the zeros that are not stored
+fn fmt_logical<'a, T, F>(f: &mut fmt::Formatter, n: usize, elem: F) -> fmt::Result where
+    F: Fn(usize, usize) -> Option<&'a T>,
+    T: 'a + fmt::Debug + Zero,
+{
+    let zero = T::zero();
+
+    for i in 0..n {
+        if i != 0 {
+            try!(f.write_str("\n"));
+        }
+
+        try!(f.write_str("["));
+
+        for j in 0..n {
+            if j != 0 {
+                try!(f.write_str(", "));
+            }
+
+            try!(write!(f, "{:?}", elem(i, j).unwrap_or(&zero)))
+        }
+
+        try!(f.write_str("]"))
+    }
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+use std::cmp;
+use std::marker::Unsized;
+use std::num::Zero;
+use std::ops::{Index, IndexMut, Mul};
+use std::raw::FatPtr;
+use std::{fat_ptr, fmt};
+
+use blas::{axpy, gemm};
+use num::Scalar;
+use ops::AsStrided;
+use strided::mat::Info;
+
+impl<T> ::structured::Symmetric<T> {
+    pub fn repr(&self) -> FatPtr<T, Info> {
+        fat_ptr::repr(self)
+    }
+
+    /// Returns the order of the matrix
+    pub fn n(&self) -> usize {
+        self.repr().info.nrows
+    }
+
+    /// Returns the whole underlying matrix, including the upper triangle which is not used
+    pub fn storage(&self) -> &::strided::Mat<T> {
+        unsafe {
+            &*fat_ptr::new(self.repr())
+        }
+    }
+
+    /// Mutable version of `storage`
+    pub fn storage_mut(&mut self) -> &mut ::strided::Mat<T> {
+        unsafe {
+            &mut *fat_ptr::new(self.repr())
+        }
+    }
+}
+
+/// Maps `(i, j)` to the lower triangle
+fn lower(i: usize, j: usize) -> (usize, usize) {
+    (cmp::max(i, j), cmp::min(i, j))
+}
+
+impl<T> fmt::Debug for ::structured::Symmetric<T> where T: fmt::Debug + Zero {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let m = self.storage();
+
+        ::structured::fmt_logical(f, self.n(), |i, j| Some(&m[lower(i, j)]))
+    }
+}
+
+/// The elements of the upper triangle are read from the lower triangle
+impl<T> Index<(usize, usize)> for ::structured::Symmetric<T> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        &self.storage()[lower(i, j)]
+    }
+}
+
+/// Writing to `(i, j)` also changes `(j, i)`
+impl<T> IndexMut<(usize, usize)> for ::structured::Symmetric<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        &mut self.storage_mut()[lower(i, j)]
+    }
+}
+
+/// Only the lower triangle is read
+impl<'a, T, R> Mul<R> for &'a ::structured::Symmetric<T> where R: AsStrided<T>, T: Scalar {
+    type Output = Box<::Mat<T>>;
+
+    fn mul(self, rhs: R) -> Box<::Mat<T>> {
+        let (a, b) = (self.storage(), rhs.as_strided());
+        let n = self.n();
+
+        ::structured::check_inner(n, b);
+
+        let mut c = ::Mat::zeros((n, b.ncols()));
+
+        // The blocks left of the diagonal block are read as is, the ones below it transposed
+        let mut i0 = 0;
+        while i0 < n {
+            let rows = i0..cmp::min(i0 + ::structured::NB, n);
+            let (left, below) = (0..rows.start, rows.end..n);
+
+            {
+                let c_rows = &mut c[(rows.clone(), ..)];
+                let a_left = &a[(rows.clone(), left.clone())];
+                let a_below = a[(below.clone(), rows.clone())].t();
+
+                gemm(T::one(), a_left, &b[(left, ..)], T::zero(), c_rows);
+                gemm(T::one(), a_below, &b[(below, ..)], T::one(), c_rows);
+            }
+
+            // Only the lower triangle of the diagonal block is stored
+            for i in rows.clone() {
+                for k in rows.start..i {
+                    let x = a[(i, k)];
+
+                    axpy(x, &b[k], &mut c[i]);
+                    axpy(x, &b[i], &mut c[k]);
+                }
+
+                axpy(a[(i, i)], &b[i], &mut c[i]);
+            }
+
+            i0 += ::structured::NB;
+        }
+
+        c
+    }
+}
+
+impl<T> Unsized for ::structured::Symmetric<T> {
+    type Data = T;
+    type Info = Info;
+
+    fn size_of_val(info: Info) -> usize {
+        <::strided::Mat<T> as Unsized>::size_of_val(info)
+    }
+}
@@ -0,0 +1,172 @@
+use std::marker::Unsized;
+use std::num::Zero;
+use std::ops::{Index, IndexMut, Mul};
+use std::raw::FatPtr;
+use std::{cmp, fat_ptr, fmt};
+
+use blas::{axpy, gemm};
+use error::Error;
+use num::{Scalar, StaticZero};
+use ops::AsStrided;
+use strided::mat::Info;
+
+/// Whether `(i, j)` belongs to the lower triangle
+fn in_lower(i: usize, j: usize) -> bool {
+    j <= i
+}
+
+/// Whether `(i, j)` belongs to the upper triangle
+fn in_upper(i: usize, j: usize) -> bool {
+    i <= j
+}
+
+/// Returns the `L * B` product if `lower` is true, or the `U * B` product, where `L` (`U`) is the
+/// lower (upper) triangle of the square `a`
+///
+/// The full blocks of the triangle are multiplied with `gemm`, only the blocks on the diagonal
+/// are multiplied row by row
+fn trmm<T>(a: &::strided::Mat<T>, lower: bool, b: &::strided::Mat<T>) -> Box<::Mat<T>> where
+    T: Scalar,
+{
+    let n = a.nrows();
+    let mut c = ::Mat::zeros((n, b.ncols()));
+
+    let mut i0 = 0;
+    while i0 < n {
+        let rows = i0..cmp::min(i0 + ::structured::NB, n);
+        // The full blocks of the triangle in these rows
+        let ks = if lower { 0..rows.start } else { rows.end..n };
+        let full = &a[(rows.clone(), ks.clone())];
+
+        gemm(T::one(), full, &b[(ks, ..)], T::zero(), &mut c[(rows.clone(), ..)]);
+
+        for i in rows.clone() {
+            let ks = if lower { rows.start..i + 1 } else { i..rows.end };
+
+            for k in ks {
+                axpy(a[(i, k)], &b[k], &mut c[i]);
+            }
+        }
+
+        i0 += ::structured::NB;
+    }
+
+    c
+}
+
+/// `$in_self` tells whether an element is stored, `$lower` whether that's the lower triangle and
+/// `$as_t` gets the transposed view
+macro_rules! triangular {
+    ($ty:ident, $t:ident, $as_t:ident, $as_t_mut:ident, $in_self:ident, $lower:expr) => {
+        impl<T> ::structured::$ty<T> {
+            pub fn repr(&self) -> FatPtr<T, Info> {
+                fat_ptr::repr(self)
+            }
+
+            /// Returns the order of the matrix
+            pub fn n(&self) -> usize {
+                self.repr().info.nrows
+            }
+
+            /// Returns the whole underlying matrix, including the triangle that is not part of
+            /// the view
+            pub fn storage(&self) -> &::strided::Mat<T> {
+                unsafe {
+                    &*fat_ptr::new(self.repr())
+                }
+            }
+
+            /// Mutable version of `storage`
+            pub fn storage_mut(&mut self) -> &mut ::strided::Mat<T> {
+                unsafe {
+                    &mut *fat_ptr::new(self.repr())
+                }
+            }
+
+            /// Returns the transposed view, no element is copied
+            pub fn t(&self) -> &::structured::$t<T> {
+                self.storage().t().$as_t()
+            }
+
+            /// Mutable version of `t`
+            pub fn t_mut(&mut self) -> &mut ::structured::$t<T> {
+                self.storage_mut().t_mut().$as_t_mut()
+            }
+
+            /// Returns the element at `(i, j)`, or `None` if the index is out of bounds or
+            /// outside of the triangle
+            ///
+            /// This is the non-panicking version of `&mut m[(i, j)]`
+            pub fn get_mut(&mut self, index: (usize, usize)) -> Option<&mut T> {
+                self.try_get_mut(index).ok()
+            }
+
+            /// Like `get_mut`, but reports why the element can't be written
+            pub fn try_get_mut(&mut self, (i, j): (usize, usize)) -> Result<&mut T, Error> {
+                try!(self.storage().try_get((i, j)));
+
+                if $in_self(i, j) {
+                    Ok(&mut self.storage_mut()[(i, j)])
+                } else {
+                    Err(Error::OutsideStructure { row: i, col: j })
+                }
+            }
+        }
+
+        impl<T> fmt::Debug for ::structured::$ty<T> where T: fmt::Debug + Zero {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let m = self.storage();
+
+                ::structured::fmt_logical(f, self.n(), |i, j| {
+                    if $in_self(i, j) { Some(&m[(i, j)]) } else { None }
+                })
+            }
+        }
+
+        /// The elements outside of the triangle read as zero
+        impl<T> Index<(usize, usize)> for ::structured::$ty<T> where T: StaticZero {
+            type Output = T;
+
+            fn index(&self, (i, j): (usize, usize)) -> &T {
+                let x = &self.storage()[(i, j)];
+
+                if $in_self(i, j) { x } else { T::zero_ref() }
+            }
+        }
+
+        /// Panics with `Error::OutsideStructure` if the element is outside of the triangle
+        impl<T> IndexMut<(usize, usize)> for ::structured::$ty<T> where T: StaticZero {
+            fn index_mut(&mut self, index: (usize, usize)) -> &mut T {
+                match self.try_get_mut(index) {
+                    Ok(x) => x,
+                    Err(e) => panic!("{}", e),
+                }
+            }
+        }
+
+        /// Only the triangle is read
+        impl<'a, T, R> Mul<R> for &'a ::structured::$ty<T> where R: AsStrided<T>, T: Scalar {
+            type Output = Box<::Mat<T>>;
+
+            fn mul(self, rhs: R) -> Box<::Mat<T>> {
+                let b = rhs.as_strided();
+
+                ::structured::check_inner(self.n(), b);
+
+                trmm(self.storage(), $lower, b)
+            }
+        }
+
+        impl<T> Unsized for ::structured::$ty<T> {
+            type Data = T;
+            type Info = Info;
+
+            fn size_of_val(info: Info) -> usize {
+                <::strided::Mat<T> as Unsized>::size_of_val(info)
+            }
+        }
+    }
+}
+
+triangular!(Lower, Upper, as_upper, as_upper_mut, in_lower, true);
+triangular!(Upper, Lower, as_lower, as_lower_mut, in_upper, false);
@@ -0,0 +1,173 @@
+//! Indices accepted by a tensor
+//!
+//! - `t[[usize; N]]` is an element, `N` must be equal to the number of axes
+//! - `&t[(A, B, ..)]` is a view, where each component is either a `usize` or a `Slice` (see the
+//!   `range` module). The axes indexed with a `usize` are dropped from the view, and the trailing
+//!   axes that are not mentioned in the tuple are kept whole: `&t[(1,)]` is the same as
+//!   `&t[(1, .., ..)]` for a 3-D tensor
+
+use std::fat_ptr;
+use std::raw::FatPtr;
+
+use error::Error;
+use range::{Indices, Slice};
+use tensor::Info;
+
+/// An index into a tensor
+pub trait TensorIndex<T> {
+    /// What the index points to: an element or a view
+    type Output: ?Sized;
+
+    /// Returns a pointer to the indexed element/view, or which bound was violated
+    ///
+    /// NB This hands out a raw pointer, the caller must tie it to the right borrow of `t`
+    fn get_raw(self, t: &::tensor::Tensor<T>) -> Result<*mut Self::Output, Error>;
+}
+
+/// What a component of a tuple index selects along its axis
+pub enum Selection {
+    /// A single position, the axis is dropped
+    Index(usize),
+    /// Several positions, the axis is kept
+    Slice(Indices),
+}
+
+/// A component of a tuple index
+pub trait Axis {
+    /// Resolves the component against the axis number `axis`, whose length is `len`
+    fn select(self, axis: usize, len: usize) -> Result<Selection, Error>;
+}
+
+impl Axis for usize {
+    fn select(self, axis: usize, len: usize) -> Result<Selection, Error> {
+        if self < len {
+            Ok(Selection::Index(self))
+        } else {
+            Err(Error::AxisOutOfBounds { axis: axis, index: self, len: len })
+        }
+    }
+}
+
+impl<S> Axis for S where S: Slice {
+    fn select(self, axis: usize, len: usize) -> Result<Selection, Error> {
+        self.try_resolve(len).map(Selection::Slice).map_err(|r| {
+            Error::AxisRangeOutOfBounds { axis: axis, start: r.start, end: r.end, len: len }
+        })
+    }
+}
+
+/// Element: `t[[1, 2, 3]]`
+macro_rules! array {
+    ($($n:expr),+) => {
+        $(
+            impl<T> TensorIndex<T> for [usize; $n] {
+                type Output = T;
+
+                fn get_raw(self, t: &::tensor::Tensor<T>) -> Result<*mut T, Error> {
+                    let FatPtr { data, info } = t.repr();
+
+                    if info.ndim != $n {
+                        return Err(Error::NdimMismatch { expected: info.ndim, found: $n })
+                    }
+
+                    let mut offset = 0;
+                    for (axis, &i) in self.iter().enumerate() {
+                        let len = info.shape[axis];
+
+                        if i >= len {
+                            return Err(Error::AxisOutOfBounds { axis: axis, index: i, len: len })
+                        }
+
+                        offset += i as isize * info.strides[axis];
+                    }
+
+                    unsafe {
+                        Ok(data.offset(offset))
+                    }
+                }
+            }
+        )+
+    }
+}
+
+array!(1, 2, 3, 4, 5, 6);
+
+/// Builds a view as the components of a tuple index are consumed, one axis at a time
+struct View<T> {
+    data: *mut T,
+    /// The indexed tensor
+    info: Info,
+    /// The view
+    view: Info,
+    /// The next axis to index
+    axis: usize,
+}
+
+impl<T> View<T> {
+    fn new(t: &::tensor::Tensor<T>, ncomponents: usize) -> Result<View<T>, Error> {
+        let FatPtr { data, info } = t.repr();
+
+        if ncomponents > info.ndim {
+            return Err(Error::NdimMismatch { expected: info.ndim, found: ncomponents })
+        }
+
+        Ok(View {
+            data: data,
+            info: info,
+            view: Info::scalar(),
+            axis: 0,
+        })
+    }
+
+    fn select<A>(&mut self, a: A) -> Result<(), Error> where A: Axis {
+        let (len, stride) = (self.info.shape[self.axis], self.info.strides[self.axis]);
+
+        match try!(a.select(self.axis, len)) {
+            Selection::Index(i) => unsafe {
+                self.data = self.data.offset(i as isize * stride);
+            },
+            Selection::Slice(indices) => unsafe {
+                self.data = self.data.offset(indices.start as isize * stride);
+                self.view.push(indices.len, stride * indices.step);
+            },
+        }
+
+        self.axis += 1;
+
+        Ok(())
+    }
+
+    fn finish(mut self) -> *mut ::tensor::Tensor<T> {
+        for axis in self.axis..self.info.ndim {
+            self.view.push(self.info.shape[axis], self.info.strides[axis]);
+        }
+
+        unsafe {
+            fat_ptr::new(FatPtr { data: self.data, info: self.view })
+        }
+    }
+}
+
+/// View: `&t[(1, .., 2..)]`
+macro_rules! tuple {
+    ($($a:ident: $i:tt),+) => {
+        impl<T, $($a),+> TensorIndex<T> for ($($a,)+) where $($a: Axis),+ {
+            type Output = ::tensor::Tensor<T>;
+
+            fn get_raw(self, t: &::tensor::Tensor<T>) -> Result<*mut ::tensor::Tensor<T>, Error> {
+                let mut view = try!(View::new(t, [$($i),+].len()));
+
+                $(try!(view.select(self.$i));)+
+
+                Ok(view.finish())
+            }
+        }
+    }
+}
+
+tuple!(A: 0);
+tuple!(A: 0, B: 1);
+tuple!(A: 0, B: 1, C: 2);
+tuple!(A: 0, B: 1, C: 2, D: 3);
+tuple!(A: 0, B: 1, C: 2, D: 3, E: 4);
+tuple!(A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
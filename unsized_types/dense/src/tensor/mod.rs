@@ -0,0 +1,432 @@
+//! N-dimensional strided arrays
+//!
+//! A `Tensor` generalizes `strided::Mat` to any number of axes: its fat pointer carries the shape
+//! and the strides of up to `MAX_NDIM` axes. The number of axes is a runtime value, the shape and
+//! strides live in fixed-size arrays so that `Info` stays `Copy`.
+//!
+//! See the `tensor::index` module for the accepted indices.
+
+use std::marker::Unsized;
+use std::ops::{Index, IndexMut};
+use std::raw::FatPtr;
+use std::{fat_ptr, fmt, mem};
+
+use error::Error;
+use tensor::index::TensorIndex;
+
+pub mod index;
+
+/// An N-dimensional strided array
+pub unsized type Tensor<T>;
+
+/// Maximum number of axes of a tensor
+pub const MAX_NDIM: usize = 6;
+
+#[derive(Clone, Copy, Debug)]
+pub struct Info {
+    /// Number of axes
+    pub ndim: usize,
+    /// Length of each axis, only the first `ndim` elements are meaningful
+    pub shape: [usize; MAX_NDIM],
+    /// Distance between two consecutive elements along each axis
+    pub strides: [isize; MAX_NDIM],
+}
+
+impl Info {
+    /// A tensor with no axes, i.e. a single element
+    fn scalar() -> Info {
+        Info {
+            ndim: 0,
+            shape: [0; MAX_NDIM],
+            strides: [0; MAX_NDIM],
+        }
+    }
+
+    /// The row-major (C order) layout of `shape`, or `Error::TooManyAxes` if `shape` has more
+    /// than `MAX_NDIM` axes
+    fn contiguous(shape: &[usize]) -> Result<Info, Error> {
+        let mut info = Info::scalar();
+        let mut stride = 1;
+
+        if shape.len() > MAX_NDIM {
+            return Err(Error::TooManyAxes { ndim: shape.len() })
+        }
+
+        info.ndim = shape.len();
+        for (axis, &len) in shape.iter().enumerate().rev() {
+            info.shape[axis] = len;
+            info.strides[axis] = stride as isize;
+            stride *= len;
+        }
+
+        Ok(info)
+    }
+
+    /// Appends an axis
+    fn push(&mut self, len: usize, stride: isize) {
+        assert!(self.ndim < MAX_NDIM);
+
+        self.shape[self.ndim] = len;
+        self.strides[self.ndim] = stride;
+        self.ndim += 1;
+    }
+
+    pub fn shape(&self) -> &[usize] {
+        &self.shape[..self.ndim]
+    }
+
+    pub fn strides(&self) -> &[isize] {
+        &self.strides[..self.ndim]
+    }
+
+    /// Number of elements
+    pub fn len(&self) -> usize {
+        self.shape().iter().fold(1, |p, &len| p * len)
+    }
+}
+
+impl<T> ::tensor::Tensor<T> {
+    /// Views the slice as a tensor of the given shape, in row-major order
+    ///
+    /// Panics with `Error::TooManyAxes` if `shape` has more than `MAX_NDIM` axes, and with
+    /// `Error::LenMismatch` if it doesn't have `slice.len()` elements
+    pub fn reshape<'a>(slice: &'a [T], shape: &[usize]) -> &'a ::tensor::Tensor<T> {
+        unsafe {
+            &*::tensor::Tensor::from_raw(slice.as_ptr() as *mut T, slice.len(), shape)
+        }
+    }
+
+    /// Mutable version of `reshape`
+    pub fn reshape_mut<'a>(slice: &'a mut [T], shape: &[usize]) -> &'a mut ::tensor::Tensor<T> {
+        unsafe {
+            &mut *::tensor::Tensor::from_raw(slice.as_mut_ptr(), slice.len(), shape)
+        }
+    }
+
+    pub fn repr(&self) -> FatPtr<T, Info> {
+        fat_ptr::repr(self)
+    }
+
+    /// Returns the number of axes
+    pub fn ndim(&self) -> usize {
+        self.repr().info.ndim
+    }
+
+    /// Returns the length of each axis
+    pub fn shape(&self) -> Vec<usize> {
+        self.repr().info.shape().to_vec()
+    }
+
+    /// Returns the number of elements
+    pub fn len(&self) -> usize {
+        self.repr().info.len()
+    }
+
+    /// Returns the element or view pointed to by `index`, or `None` if the index is out of bounds
+    pub fn get<I>(&self, index: I) -> Option<&I::Output> where I: TensorIndex<T> {
+        self.try_get(index).ok()
+    }
+
+    /// Mutable version of `get`
+    pub fn get_mut<I>(&mut self, index: I) -> Option<&mut I::Output> where I: TensorIndex<T> {
+        self.try_get_mut(index).ok()
+    }
+
+    /// Like `get`, but reports which bound was violated
+    pub fn try_get<I>(&self, index: I) -> Result<&I::Output, Error> where I: TensorIndex<T> {
+        index.get_raw(self).map(|ptr| unsafe { &*ptr })
+    }
+
+    /// Mutable version of `try_get`
+    pub fn try_get_mut<I>(&mut self, index: I) -> Result<&mut I::Output, Error> where
+        I: TensorIndex<T>,
+    {
+        index.get_raw(self).map(|ptr| unsafe { &mut *ptr })
+    }
+
+    /// Returns a view whose axis `i` is the axis `axes[i]` of this tensor, no element is copied
+    ///
+    /// Panics with `Error::NdimMismatch` if `axes` doesn't have `ndim` elements, and with
+    /// `Error::NotPermutation` if it's not a permutation of `0..ndim`
+    pub fn permute(&self, axes: &[usize]) -> &::tensor::Tensor<T> {
+        unsafe {
+            &*self.permute_raw(axes)
+        }
+    }
+
+    /// Mutable version of `permute`
+    pub fn permute_mut(&mut self, axes: &[usize]) -> &mut ::tensor::Tensor<T> {
+        unsafe {
+            &mut *self.permute_raw(axes)
+        }
+    }
+
+    /// Returns a view with the axes in reverse order
+    pub fn t(&self) -> &::tensor::Tensor<T> {
+        let axes = (0..self.ndim()).rev().collect::<Vec<_>>();
+
+        self.permute(&axes)
+    }
+
+    /// Returns a view of the same elements, in row-major order, with a different shape
+    ///
+    /// No element is copied, so this fails with `Error::NotContiguous` if the axes that have to
+    /// be merged are not laid out contiguously. Returns `Error::LenMismatch` if `shape` doesn't
+    /// have the same number of elements, and `Error::TooManyAxes` if it has more than `MAX_NDIM`
+    /// axes
+    pub fn reshaped(&self, shape: &[usize]) -> Result<&::tensor::Tensor<T>, Error> {
+        self.reshaped_raw(shape).map(|ptr| unsafe { &*ptr })
+    }
+
+    /// Mutable version of `reshaped`
+    pub fn reshaped_mut(&mut self, shape: &[usize]) -> Result<&mut ::tensor::Tensor<T>, Error> {
+        self.reshaped_raw(shape).map(|ptr| unsafe { &mut *ptr })
+    }
+
+    /// Views a 2-D tensor as a strided matrix
+    ///
+    /// Panics with `Error::NdimMismatch` if the tensor doesn't have exactly two axes
+    pub fn as_mat(&self) -> &::strided::Mat<T> {
+        unsafe {
+            &*self.as_mat_raw()
+        }
+    }
+
+    /// Mutable version of `as_mat`
+    pub fn as_mat_mut(&mut self) -> &mut ::strided::Mat<T> {
+        unsafe {
+            &mut *self.as_mat_raw()
+        }
+    }
+
+    /// Panics with `Error::TooManyAxes` if `shape` has too many axes, and with
+    /// `Error::LenMismatch` if it doesn't have `len` elements
+    unsafe fn from_raw(data: *mut T, len: usize, shape: &[usize]) -> *mut ::tensor::Tensor<T> {
+        let info = match Info::contiguous(shape) {
+            Ok(info) => info,
+            Err(e) => panic!("{}", e),
+        };
+
+        if info.len() != len {
+            panic!("{}", Error::LenMismatch { lhs: len, rhs: info.len() })
+        }
+
+        fat_ptr::new(FatPtr { data: data, info: info })
+    }
+
+    /// Panicking version of `TensorIndex::get_raw`
+    fn index_raw<I>(&self, index: I) -> *mut I::Output where I: TensorIndex<T> {
+        match index.get_raw(self) {
+            Ok(ptr) => ptr,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    fn permute_raw(&self, axes: &[usize]) -> *mut ::tensor::Tensor<T> {
+        let FatPtr { data, info } = self.repr();
+
+        if axes.len() != info.ndim {
+            panic!("{}", Error::NdimMismatch { expected: info.ndim, found: axes.len() })
+        }
+
+        let mut permuted = Info::scalar();
+        let mut seen = [false; MAX_NDIM];
+        for &axis in axes {
+            if axis >= info.ndim || seen[axis] {
+                panic!("{}", Error::NotPermutation { index: axis })
+            }
+
+            seen[axis] = true;
+            permuted.push(info.shape[axis], info.strides[axis]);
+        }
+
+        unsafe {
+            fat_ptr::new(FatPtr { data: data, info: permuted })
+        }
+    }
+
+    /// Adapted from NumPy's `_attempt_nocopy_reshape`: the old axes are grouped with the new axes
+    /// that span the same elements, and each group of old axes must be contiguous
+    fn reshaped_raw(&self, shape: &[usize]) -> Result<*mut ::tensor::Tensor<T>, Error> {
+        let FatPtr { data, info } = self.repr();
+        let mut new = try!(Info::contiguous(shape));
+
+        if new.len() != info.len() {
+            return Err(Error::LenMismatch { lhs: info.len(), rhs: new.len() })
+        }
+
+        if info.len() != 0 {
+            // Axes of length one don't constrain the layout
+            let (old_shape, old_strides): (Vec<_>, Vec<_>) = info.shape().iter()
+                .zip(info.strides())
+                .filter(|&(&len, _)| len != 1)
+                .map(|(&len, &stride)| (len, stride))
+                .unzip();
+
+            let (mut oi, mut oj, mut ni, mut nj) = (0, 1, 0, 1);
+            while ni < shape.len() && oi < old_shape.len() {
+                let (mut np, mut op) = (shape[ni], old_shape[oi]);
+
+                while np != op {
+                    if np < op {
+                        np *= shape[nj];
+                        nj += 1;
+                    } else {
+                        op *= old_shape[oj];
+                        oj += 1;
+                    }
+                }
+
+                for k in oi..oj - 1 {
+                    if old_strides[k] != old_shape[k + 1] as isize * old_strides[k + 1] {
+                        return Err(Error::NotContiguous)
+                    }
+                }
+
+                new.strides[nj - 1] = old_strides[oj - 1];
+                for k in (ni + 1..nj).rev() {
+                    new.strides[k - 1] = new.strides[k] * shape[k] as isize;
+                }
+
+                ni = nj;
+                nj += 1;
+                oi = oj;
+                oj += 1;
+            }
+        }
+
+        unsafe {
+            Ok(fat_ptr::new(FatPtr { data: data, info: new }))
+        }
+    }
+
+    unsafe fn as_mat_raw(&self) -> *mut ::strided::Mat<T> {
+        let FatPtr { data, info } = self.repr();
+
+        if info.ndim != 2 {
+            panic!("{}", Error::NdimMismatch { expected: 2, found: info.ndim })
+        }
+
+        fat_ptr::new(FatPtr {
+            data: data,
+            info: ::strided::mat::Info {
+                nrows: info.shape[0],
+                ncols: info.shape[1],
+                row_stride: info.strides[0],
+                col_stride: info.strides[1],
+            }
+        })
+    }
+}
+
+impl<T> ::strided::Mat<T> {
+    /// Views the matrix as a 2-D tensor
+    pub fn as_tensor(&self) -> &::tensor::Tensor<T> {
+        unsafe {
+            &*self.as_tensor_raw()
+        }
+    }
+
+    /// Mutable version of `as_tensor`
+    pub fn as_tensor_mut(&mut self) -> &mut ::tensor::Tensor<T> {
+        unsafe {
+            &mut *self.as_tensor_raw()
+        }
+    }
+
+    unsafe fn as_tensor_raw(&self) -> *mut ::tensor::Tensor<T> {
+        let FatPtr { data, info } = self.repr();
+        let mut t = Info::scalar();
+
+        t.push(info.nrows, info.row_stride);
+        t.push(info.ncols, info.col_stride);
+
+        fat_ptr::new(FatPtr { data: data, info: t })
+    }
+}
+
+/// Prints the sub-tensor that starts at `data` and spans the axes `axis..`, like NumPy does
+unsafe fn fmt_axis<T>(
+    f: &mut fmt::Formatter,
+    data: *const T,
+    info: &Info,
+    axis: usize,
+) -> fmt::Result where
+    T: fmt::Debug,
+{
+    if axis == info.ndim {
+        return write!(f, "{:?}", *data)
+    }
+
+    try!(f.write_str("["));
+
+    for i in 0..info.shape[axis] {
+        if i != 0 {
+            if axis + 1 == info.ndim {
+                try!(f.write_str(", "));
+            } else {
+                // One line break per inner axis, then align with the opening brackets
+                try!(f.write_str(","));
+
+                for _ in axis + 1..info.ndim {
+                    try!(f.write_str("\n"));
+                }
+
+                for _ in 0..axis + 1 {
+                    try!(f.write_str(" "));
+                }
+            }
+        }
+
+        try!(fmt_axis(f, data.offset(i as isize * info.strides[axis]), info, axis + 1));
+    }
+
+    f.write_str("]")
+}
+
+impl<T> fmt::Debug for ::tensor::Tensor<T> where T: fmt::Debug {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let FatPtr { data, info } = self.repr();
+
+        unsafe {
+            fmt_axis(f, data, &info, 0)
+        }
+    }
+}
+
+/// See the `tensor::index` module for the accepted indices
+impl<T, I> Index<I> for ::tensor::Tensor<T> where I: TensorIndex<T> {
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &I::Output {
+        unsafe {
+            &*self.index_raw(index)
+        }
+    }
+}
+
+impl<T, I> IndexMut<I> for ::tensor::Tensor<T> where I: TensorIndex<T> {
+    fn index_mut(&mut self, index: I) -> &mut I::Output {
+        unsafe {
+            &mut *self.index_raw(index)
+        }
+    }
+}
+
+impl<T> Unsized for ::tensor::Tensor<T> {
+    type Data = T;
+    type Info = Info;
+
+    /// The span of memory between the first and the last element
+    fn size_of_val(info: Info) -> usize {
+        if info.len() == 0 {
+            0
+        } else {
+            let last = info.shape().iter().zip(info.strides()).fold(0, |last, (&len, &stride)| {
+                last + (len - 1) * stride.abs() as usize
+            });
+
+            (last + 1) * mem::size_of::<T>()
+        }
+    }
+}
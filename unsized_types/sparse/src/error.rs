@@ -0,0 +1,42 @@
+use std::{error, fmt};
+
+/// Error returned by the non-panicking accessors, it reports why an index is not valid
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Error {
+    /// The column index is not less than the number of columns
+    ColOutOfBounds { col: usize, ncols: usize },
+    /// The index is within bounds, but the element is not stored in the sparse matrix
+    NotSet,
+    /// The row index is not less than the number of rows
+    RowOutOfBounds { row: usize, nrows: usize },
+    /// The row range `start..end` is inverted or doesn't fit in `nrows` rows
+    RowRangeOutOfBounds { start: usize, end: usize, nrows: usize },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::ColOutOfBounds { col, ncols } => {
+                write!(f, "column index {} out of bounds (ncols = {})", col, ncols)
+            },
+            Error::NotSet => f.write_str("element not set"),
+            Error::RowOutOfBounds { row, nrows } => {
+                write!(f, "row index {} out of bounds (nrows = {})", row, nrows)
+            },
+            Error::RowRangeOutOfBounds { start, end, nrows } => {
+                write!(f, "row range {}..{} out of bounds (nrows = {})", start, end, nrows)
+            },
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn description(&self) -> &str {
+        match *self {
+            Error::ColOutOfBounds { .. } => "column index out of bounds",
+            Error::NotSet => "element not set",
+            Error::RowOutOfBounds { .. } => "row index out of bounds",
+            Error::RowRangeOutOfBounds { .. } => "row range out of bounds",
+        }
+    }
+}
@@ -3,6 +3,7 @@
 #![feature(raw)]
 #![feature(unsized_types)]
 
+mod error;
 mod mat;
 mod row;
 mod vector;
@@ -16,6 +17,13 @@ macro_rules! show {
     }
 }
 
+macro_rules! display {
+    ($fmt:tt, $e:expr) => {
+        println!("> println!({:?}, {})", $fmt, stringify!($e));
+        println!(concat!($fmt, "\n"), $e);
+    }
+}
+
 fn main() {
     let data;
     show!(data = Box::new([1, 2, 3, 4, 5, 6, 7, 8]));
@@ -58,6 +66,18 @@ fn main() {
     println!("// `&m[1..3]: &'m Mat<i32>`");
     show!(&m[1..3]);
 
+    println!("// `Display` aligns the columns, the alternate flag prints a density summary");
+    display!("{}", m);
+
+    display!("{:#}", m);
+
+    println!("// Non-panicking accessors");
+    show!(m.get((1, 2)));
+
+    show!(m.try_get((1, 2)));
+
+    show!(m.try_get((5, 0)));
+
     show!(mem::drop(m));
 }
 
@@ -1,7 +1,9 @@
 use std::marker::Unsized;
 use std::ops::{Index, Range};
 use std::raw::FatPtr;
-use std::{fat_ptr, fmt, mem, ptr, slice};
+use std::{cmp, fat_ptr, fmt, mem, ptr, slice};
+
+use error::Error;
 
 #[allow(raw_pointer_derive)]
 #[derive(Clone, Copy, Debug)]
@@ -60,6 +62,19 @@ impl<T> ::Mat<T> {
     pub fn nrows(&self) -> usize {
         self.repr().info.nrows
     }
+
+    /// Returns the element, row or submatrix pointed to by `index`, or `None` if the index is out
+    /// of bounds or the element is not set
+    ///
+    /// This is the non-panicking version of `&m[index]`
+    pub fn get<I>(&self, index: I) -> Option<&I::Output> where I: MatIndex<T> {
+        self.try_get(index).ok()
+    }
+
+    /// Like `get`, but reports why the index is not valid
+    pub fn try_get<I>(&self, index: I) -> Result<&I::Output, Error> where I: MatIndex<T> {
+        index.get(self)
+    }
 }
 
 impl<T> fmt::Debug for ::Mat<T> where T: fmt::Debug {
@@ -79,6 +94,74 @@ impl<T> fmt::Debug for ::Mat<T> where T: fmt::Debug {
     }
 }
 
+/// Aligned version of `Debug`: every column is right-aligned to its widest element and the
+/// precision flag (`{:.3}`) is forwarded to the elements. Axes longer than `THRESHOLD` are elided
+/// NumPy-style, with `...` in place of the inner rows/columns
+///
+/// The alternate flag (`{:#}`) prints a density summary instead of the elements
+impl<T> fmt::Display for ::Mat<T> where T: fmt::Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let FatPtr { info, .. } = self.repr();
+
+        if f.alternate() {
+            let len = info.nrows * info.ncols;
+            let density = if len == 0 { 0. } else { 100. * info.nnz as f64 / len as f64 };
+
+            return write!(f, "{}x{} sparse matrix, {} stored elements ({:.1}% dense)",
+                          info.nrows, info.ncols, info.nnz, density)
+        }
+
+        let precision = f.precision();
+        let cols = shown(info.ncols);
+
+        // The formatted elements of the shown rows, `None` stands for the elided rows
+        let rows: Vec<Option<Vec<String>>> = shown(info.nrows).into_iter().map(|i| i.map(|i| {
+            let row = &self[i];
+
+            cols.iter().map(|&j| match j.map(|j| row.get(j)) {
+                Some(Some(x)) => match precision {
+                    Some(precision) => format!("{:.*}", precision, x),
+                    None => x.to_string(),
+                },
+                Some(None) => "_".to_string(),
+                None => "...".to_string(),
+            }).collect()
+        })).collect();
+
+        let mut widths = vec![0; cols.len()];
+        for row in rows.iter().filter_map(|row| row.as_ref()) {
+            for (width, elem) in widths.iter_mut().zip(row) {
+                *width = cmp::max(*width, elem.chars().count());
+            }
+        }
+
+        for (i, row) in rows.iter().enumerate() {
+            if i != 0 {
+                try!(f.write_str("\n"));
+            }
+
+            match *row {
+                None => try!(f.write_str("...")),
+                Some(ref row) => {
+                    try!(f.write_str("["));
+
+                    for (j, (elem, &width)) in row.iter().zip(&widths).enumerate() {
+                        if j != 0 {
+                            try!(f.write_str(", "));
+                        }
+
+                        try!(write!(f, "{:>1$}", elem, width))
+                    }
+
+                    try!(f.write_str("]"))
+                },
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl<T> Drop for ::Mat<T> {
     fn drop(&mut self) {
         let FatPtr { data, info } = self.repr();
@@ -98,41 +181,59 @@ impl<T> Drop for ::Mat<T> {
     }
 }
 
-/// Element indexing
-impl<T> Index<(usize, usize)> for ::Mat<T> {
-    type Output = T;
+/// Axes longer than this are elided by `Display`
+const THRESHOLD: usize = 10;
+/// Number of rows/columns shown at each end of an elided axis
+const EDGE_ITEMS: usize = 3;
 
-    fn index(&self, (i, j): (usize, usize)) -> &T {
-        unsafe {
-            let FatPtr { data, info } = self.repr();
+/// The indices of an axis of length `len` that are shown, `None` stands for the elided ones
+fn shown(len: usize) -> Vec<Option<usize>> {
+    if len > THRESHOLD {
+        let mut shown: Vec<_> = (0..EDGE_ITEMS).map(Some).collect();
+
+        shown.push(None);
+        shown.extend((len - EDGE_ITEMS..len).map(Some));
 
-            assert!(i < info.nrows);
-            assert!(j < info.ncols);
+        shown
+    } else {
+        (0..len).map(Some).collect()
+    }
+}
 
-            for k in *info.row_ptr.offset(i as isize)..*info.row_ptr.offset(i as isize + 1) {
-                let k = k as isize;
+/// An index into a sparse matrix: `(usize, usize)` (element), `usize` (row) or `Range<usize>`
+/// (rows)
+pub trait MatIndex<T> {
+    /// What the index points to: an element, a row or a submatrix
+    type Output: ?Sized;
 
-                if j == *info.col_ind.offset(k) {
-                    return &*data.offset(k)
-                }
-            }
+    /// Returns the indexed element/view, or the reason why the index is not valid
+    fn get(self, m: &::Mat<T>) -> Result<&Self::Output, Error>;
+}
 
-            panic!("element not set");
-        }
+/// Element indexing
+impl<T> MatIndex<T> for (usize, usize) {
+    type Output = T;
+
+    fn get(self, m: &::Mat<T>) -> Result<&T, Error> {
+        let (i, j) = self;
+
+        try!(i.get(m)).try_get(j)
     }
 }
 
 /// Row indexing
-impl<T> Index<usize> for ::Mat<T> {
+impl<T> MatIndex<T> for usize {
     type Output = ::Row<T>;
 
-    fn index(&self, i: usize) -> &::Row<T> {
+    fn get(self, m: &::Mat<T>) -> Result<&::Row<T>, Error> {
         unsafe {
-            let FatPtr { data, info } = self.repr();
+            let FatPtr { data, info } = m.repr();
 
-            assert!(i < info.nrows);
+            if self >= info.nrows {
+                return Err(Error::RowOutOfBounds { row: self, nrows: info.nrows })
+            }
 
-            let i = i as isize;
+            let i = self as isize;
             let offset = *info.row_ptr.offset(i);
             let nnz = *info.row_ptr.offset(i + 1) - offset;
             let offset = offset as isize;
@@ -147,26 +248,28 @@ impl<T> Index<usize> for ::Mat<T> {
                 }
             });
 
-            mem::transmute(v)
+            Ok(mem::transmute(v))
         }
     }
 }
 
 /// Row slicing
-impl<T> Index<Range<usize>> for ::Mat<T> {
+impl<T> MatIndex<T> for Range<usize> {
     type Output = ::Mat<T>;
 
-    fn index(&self, Range { start, end }: Range<usize>) -> &::Mat<T> {
+    fn get(self, m: &::Mat<T>) -> Result<&::Mat<T>, Error> {
         unsafe {
-            let FatPtr { data, info } = self.repr();
+            let FatPtr { data, info } = m.repr();
+            let Range { start, end } = self;
 
-            assert!(start <= end);
-            assert!(end <= info.nrows);
+            if start > end || end > info.nrows {
+                return Err(Error::RowRangeOutOfBounds { start: start, end: end, nrows: info.nrows })
+            }
 
             let row_ptr = info.row_ptr.offset(start as isize);
-            let nnz = *row_ptr.offset(start as isize + 1) - *row_ptr;
+            let nnz = *row_ptr.offset((end - start) as isize) - *row_ptr;
 
-            &*fat_ptr::new(FatPtr {
+            Ok(&*fat_ptr::new(FatPtr {
                 data: data,
                 info: Info {
                     col_ind: info.col_ind,
@@ -175,7 +278,19 @@ impl<T> Index<Range<usize>> for ::Mat<T> {
                     ncols: info.ncols,
                     nrows: end - start,
                 }
-            })
+            }))
+        }
+    }
+}
+
+/// See `MatIndex` for the accepted indices
+impl<T, I> Index<I> for ::Mat<T> where I: MatIndex<T> {
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &I::Output {
+        match index.get(self) {
+            Ok(x) => x,
+            Err(e) => panic!("{}", e),
         }
     }
 }
@@ -1,5 +1,19 @@
 use std::ops::Index;
 
+use error::Error;
+
+impl<T> ::Row<T> {
+    /// Returns the `i`-th element, or `None` if it's out of bounds or not set
+    pub fn get(&self, i: usize) -> Option<&T> {
+        self.0.get(i)
+    }
+
+    /// Like `get`, but reports why the element couldn't be retrieved
+    pub fn try_get(&self, i: usize) -> Result<&T, Error> {
+        self.0.try_get(i)
+    }
+}
+
 impl<T> Index<usize> for ::Row<T> {
     type Output = T;
 
@@ -3,6 +3,8 @@ use std::ops::Index;
 use std::raw::FatPtr;
 use std::{fat_ptr, fmt, mem, slice};
 
+use error::Error;
+
 #[allow(raw_pointer_derive)]
 #[derive(Clone, Copy)]
 pub struct Info {
@@ -18,6 +20,32 @@ impl<T> ::Vector<T> {
     fn repr(&self) -> FatPtr<T, Info> {
         fat_ptr::repr(self)
     }
+
+    /// Returns the `i`-th element, or `None` if it's out of bounds or not set
+    pub fn get(&self, i: usize) -> Option<&T> {
+        self.try_get(i).ok()
+    }
+
+    /// Like `get`, but reports why the element couldn't be retrieved
+    pub fn try_get(&self, i: usize) -> Result<&T, Error> {
+        unsafe {
+            let FatPtr { data, info } = self.repr();
+
+            if i >= info.len {
+                return Err(Error::ColOutOfBounds { col: i, ncols: info.len })
+            }
+
+            for (&j, k) in slice::from_raw_parts(info.indices, info.nnz).iter().zip(0..) {
+                if j > i {
+                    break
+                } else if j == i {
+                    return Ok(&*data.offset(k))
+                }
+            }
+
+            Err(Error::NotSet)
+        }
+    }
 }
 
 impl<T> fmt::Debug for ::Vector<T> where T: fmt::Debug {
@@ -53,18 +81,9 @@ impl<T> Index<usize> for ::Vector<T> {
     type Output = T;
 
     fn index(&self, i: usize) -> &T {
-        unsafe {
-            let FatPtr { data, info } = self.repr();
-
-            for (&j, k) in slice::from_raw_parts(info.indices, info.nnz).iter().zip(0..) {
-                if j > i {
-                    break
-                } else if j == i {
-                    return &*data.offset(k)
-                }
-            }
-
-            panic!("element not set");
+        match self.try_get(i) {
+            Ok(x) => x,
+            Err(e) => panic!("{}", e),
         }
     }
 }